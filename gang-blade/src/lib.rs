@@ -0,0 +1,23 @@
+//! Blade arithmetic shared by the `gang!` macro and the runtime `gang::dynamic` module.
+//!
+//! Blades are bitmasks of their basis vectors, e.g. `0b101` is `e02`.
+
+#![no_std]
+
+/// Sign of reordering the vectors of `lhs` followed by those of `rhs` into increasing order,
+/// 1 or -1.
+///
+/// Each vector of `lhs` has to swap with every lower vector of `rhs`.
+pub const fn reordering_sign(lhs: u32, rhs: u32) -> i32 {
+	let mut lhs = lhs >> 1;
+	let mut swaps = 0;
+	while lhs != 0 {
+		swaps += (lhs & rhs).count_ones();
+		lhs >>= 1;
+	}
+	if swaps % 2 == 0 {
+		1
+	} else {
+		-1
+	}
+}
@@ -0,0 +1,9 @@
+//! Algebras generated by a crate that only depends on `gang`.
+
+pub mod g3 {
+	gang::gang!(3);
+}
+
+pub mod g4 {
+	gang::gang!(4, products = [geometric, rotate], grades = [1, 2]);
+}
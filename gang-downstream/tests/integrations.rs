@@ -0,0 +1,42 @@
+//! The integrations of the generated types work through the re-exports of `gang`.
+
+use gang::Rotate;
+use gang_downstream::{g3, g4};
+
+#[test]
+fn serde() {
+	let v = g3::V1::new(1.0, -2.0, 0.5);
+	let json = serde_json::to_string(&v).unwrap();
+	assert_eq!(serde_json::from_str::<g3::V1>(&json).unwrap(), v);
+
+	let rot = g4::Rot::from_v2_angle(g4::V2::E01, 0.5);
+	let json = serde_json::to_string(&rot).unwrap();
+	assert_eq!(serde_json::from_str::<g4::Rot>(&json).unwrap(), rot);
+}
+
+#[test]
+fn conversions() {
+	let v = g3::V1::new(1.0, 2.0, 3.0);
+	let rot = g3::Rot::from_v2_angle(g3::V2::E01, 1.0);
+	let quat: gang::__private::glam::Quat = rot.into();
+	let rotated = g3::V1::from(quat * gang::__private::glam::Vec3::from(v));
+	assert!(rot.rotate(v).abs_diff_eq(rotated, 1e-5));
+
+	let m: gang::__private::mint::Vector3<f32> = v.into();
+	assert_eq!(g3::V1::from(m), v);
+	let n: gang::__private::nalgebra::Vector3<f32> = v.into();
+	assert_eq!(g3::V1::from(n), v);
+	assert_eq!(
+		gang::__private::bytemuck::cast::<_, [f32; 3]>(v),
+		v.to_array()
+	);
+}
+
+#[test]
+fn reflect() {
+	let mut registry = gang::__private::bevy_reflect::TypeRegistry::new();
+	g3::register_types(&mut registry);
+	g4::register_types(&mut registry);
+	assert!(registry.contains(core::any::TypeId::of::<g3::Mv>()));
+	assert!(registry.contains(core::any::TypeId::of::<g4::Rot>()));
+}
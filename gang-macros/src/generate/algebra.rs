@@ -0,0 +1,84 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{MvKind, Options};
+
+/// Implementations of the generic traits, and the `Algebra` struct tying the types together
+pub(crate) fn impl_algebra(gen: &mut Vec<TokenStream>, dim: u32, options: &Options) {
+	for &k in &options.grades {
+		let kind = MvKind::KVector(k);
+		gen.push(quote! {
+			impl gang::Norm for #kind {
+				fn norm(self) -> f32 {
+					#kind::norm(self)
+				}
+
+				fn normalize(self) -> Self {
+					#kind::normalize(self)
+				}
+			}
+
+			impl gang::Vector for #kind {
+				const ZERO: Self = #kind::ZERO;
+
+				fn normalize_or_zero(self) -> Self {
+					#kind::normalize_or_zero(self)
+				}
+
+				fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+					#kind::abs_diff_eq(self, rhs, max_abs_diff)
+				}
+			}
+		});
+	}
+
+	gen.push(quote! {
+		impl gang::Norm for Rot {
+			fn norm(self) -> f32 {
+				Rot::norm(self)
+			}
+
+			fn normalize(self) -> Self {
+				Rot::normalize(self)
+			}
+		}
+	});
+
+	// there is no rotation plane in 1D
+	if dim < 2 || !options.has_rot_mul() {
+		return;
+	}
+	gen.push(quote! {
+		impl gang::Rotor for Rot {
+			type V2 = V2;
+
+			const IDENTITY: Self = Rot::IDENTITY;
+
+			fn from_v2_angle(v2: V2, angle: f32) -> Self {
+				Rot::from_v2_angle(v2, angle)
+			}
+
+			fn abs_diff_eq_up_to_sign(self, rhs: Self, max_abs_diff: f32) -> bool {
+				Rot::abs_diff_eq_up_to_sign(self, rhs, max_abs_diff)
+			}
+		}
+	});
+
+	if !options.rotate.contains(&1) || !options.wedge.contains(&(1, 1)) {
+		return;
+	}
+	let dim = dim as usize;
+	gen.push(quote! {
+		/// The types of this module, to use with the code generic over [`gang::Algebra`]
+		#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+		pub struct Algebra;
+
+		impl gang::Algebra for Algebra {
+			const DIM: usize = #dim;
+
+			type V1 = V1;
+			type V2 = V2;
+			type Rot = Rot;
+		}
+	});
+}
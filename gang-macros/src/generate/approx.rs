@@ -0,0 +1,73 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::util::{basis_names, Basis};
+
+/// Implementations of the `approx` traits, comparing components one by one
+pub fn impl_approx(name: &Ident, basis: &Basis) -> TokenStream {
+	if !cfg!(feature = "approx") {
+		return TokenStream::new();
+	}
+
+	let els = basis_names(basis);
+	quote! {
+		impl gang::__private::approx::AbsDiffEq for #name {
+			type Epsilon = f32;
+
+			fn default_epsilon() -> f32 {
+				<f32 as gang::__private::approx::AbsDiffEq>::default_epsilon()
+			}
+
+			fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+				#(
+					gang::__private::approx::AbsDiffEq::abs_diff_eq(&self.#els, &other.#els, epsilon)
+				)&&*
+			}
+		}
+
+		impl gang::__private::approx::RelativeEq for #name {
+			fn default_max_relative() -> f32 {
+				<f32 as gang::__private::approx::RelativeEq>::default_max_relative()
+			}
+
+			fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+				#(
+					gang::__private::approx::RelativeEq::relative_eq(&self.#els, &other.#els, epsilon, max_relative)
+				)&&*
+			}
+		}
+
+		impl gang::__private::approx::UlpsEq for #name {
+			fn default_max_ulps() -> u32 {
+				<f32 as gang::__private::approx::UlpsEq>::default_max_ulps()
+			}
+
+			fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+				#(
+					gang::__private::approx::UlpsEq::ulps_eq(&self.#els, &other.#els, epsilon, max_ulps)
+				)&&*
+			}
+		}
+	}
+}
+
+/// Comparisons treating `R` and `-R` as equal, since they represent the same rotation
+pub fn impl_rotor_approx(gen: &mut Vec<TokenStream>) {
+	if !cfg!(feature = "approx") {
+		return;
+	}
+
+	gen.push(quote! {
+		impl Rot {
+			pub fn relative_eq_up_to_sign(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+				gang::__private::approx::RelativeEq::relative_eq(self, other, epsilon, max_relative)
+					|| gang::__private::approx::RelativeEq::relative_eq(self, &(*other * -1.0), epsilon, max_relative)
+			}
+
+			pub fn ulps_eq_up_to_sign(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+				gang::__private::approx::UlpsEq::ulps_eq(self, other, epsilon, max_ulps)
+					|| gang::__private::approx::UlpsEq::ulps_eq(self, &(*other * -1.0), epsilon, max_ulps)
+			}
+		}
+	});
+}
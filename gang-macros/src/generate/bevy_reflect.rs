@@ -0,0 +1,46 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Derives `Reflect`, along with the type data of the traits the type implements
+pub fn maybe_bevy_reflect(serde: bool) -> TokenStream {
+	if !cfg!(feature = "bevy_reflect") {
+		return TokenStream::new();
+	}
+
+	let maybe_serde = if serde && cfg!(feature = "serde") {
+		quote! {
+			#[reflect(Serialize, Deserialize)]
+		}
+	} else {
+		TokenStream::new()
+	};
+
+	quote! {
+		#[derive(gang::__private::bevy_reflect::Reflect)]
+		#[reflect(Clone, Default, PartialEq, Debug)]
+		#maybe_serde
+	}
+}
+
+/// Brings `bevy_reflect` and the type data used by `maybe_bevy_reflect` into scope, and registers
+/// the types
+///
+/// The derive refers to `bevy_reflect` by name when the invoking crate does not depend on it.
+/// The names come from a glob import, which the items of the invoking module shadow.
+pub fn impl_bevy_reflect(gen: &mut Vec<TokenStream>, names: &[Ident]) {
+	if !cfg!(feature = "bevy_reflect") {
+		return;
+	}
+
+	gen.push(quote! {
+		#[allow(unused_imports)]
+		use gang::__private::reflect::*;
+
+		/// Registers every type of this module
+		pub fn register_types(registry: &mut gang::__private::bevy_reflect::TypeRegistry) {
+			#(
+				registry.register::<#names>();
+			)*
+		}
+	});
+}
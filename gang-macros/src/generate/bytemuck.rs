@@ -0,0 +1,21 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// `Pod` and `Zeroable` for a generated struct made of `floats` `f32`s
+pub fn impl_bytemuck(name: &Ident, floats: usize) -> TokenStream {
+	if !cfg!(feature = "bytemuck") {
+		return TokenStream::new();
+	}
+
+	let size = floats * 4;
+	quote! {
+		// the size check rules out any padding the invariant below relies on
+		const _: () = assert!(core::mem::size_of::<#name>() == #size);
+
+		// SAFETY: the struct is `#[repr(C)]` and only made of `f32`s, without padding,
+		// so every bit pattern is valid, including all zeros
+		unsafe impl gang::__private::bytemuck::Zeroable for #name {}
+		// SAFETY: as above, and it is `Copy` and `'static`
+		unsafe impl gang::__private::bytemuck::Pod for #name {}
+	}
+}
@@ -0,0 +1,84 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+	util::{basis_names, Basis},
+	Options,
+};
+
+/// Conversions to and from `glam` types, for the dimensions that glam covers
+pub(crate) fn impl_glam(gen: &mut Vec<TokenStream>, v1_basis: &Basis, options: &Options) {
+	if !cfg!(feature = "glam") {
+		return;
+	}
+
+	let dim = v1_basis.0.len();
+	if !(2..=4).contains(&dim) {
+		return;
+	}
+
+	let els = basis_names(v1_basis);
+	let fields = ["x", "y", "z", "w"][..dim]
+		.iter()
+		.map(|f| format_ident!("{f}"))
+		.collect::<Vec<_>>();
+	let vector = format_ident!("Vec{dim}");
+
+	gen.push(quote! {
+		impl From<V1> for gang::__private::glam::#vector {
+			fn from(v: V1) -> Self {
+				Self::new(#(v.#els,)*)
+			}
+		}
+
+		impl From<gang::__private::glam::#vector> for V1 {
+			fn from(v: gang::__private::glam::#vector) -> Self {
+				Self {
+					#(
+						#els: v.#fields,
+					)*
+				}
+			}
+		}
+	});
+
+	match dim {
+		2 if options.rotate.contains(&1) => gen.push(quote! {
+			impl From<Rot> for gang::__private::glam::Mat2 {
+				fn from(r: Rot) -> Self {
+					Self::from_cols_array(&r.v1_rotation_matrix())
+				}
+			}
+
+			impl From<gang::__private::glam::Mat2> for Rot {
+				fn from(m: gang::__private::glam::Mat2) -> Self {
+					Self::from_angle(gang::math::atan2(m.x_axis.y, m.x_axis.x))
+				}
+			}
+		}),
+		3 => gen.push(quote! {
+			/// The rotor `e + e01 E01 + e02 E02 + e12 E12` maps to the quaternion
+			/// `x = -e12, y = e02, z = -e01, w = e`.
+			///
+			/// Each bivector plane is dual to the axis it rotates around, with a sign
+			/// that accounts for the orientation of the plane relative to the axis.
+			impl From<Rot> for gang::__private::glam::Quat {
+				fn from(r: Rot) -> Self {
+					Self::from_xyzw(-r.e12, r.e02, -r.e01, r.e)
+				}
+			}
+
+			impl From<gang::__private::glam::Quat> for Rot {
+				fn from(q: gang::__private::glam::Quat) -> Self {
+					Self {
+						e: q.w,
+						e01: -q.z,
+						e02: q.y,
+						e12: -q.x,
+					}
+				}
+			}
+		}),
+		_ => {}
+	}
+}
@@ -0,0 +1,72 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::util::{basis_names, Basis};
+
+pub fn std140_name(name: &Ident) -> Ident {
+	format_ident!("{}Std140", name)
+}
+
+/// Mirror types following the memory layouts of GPU buffers.
+///
+/// They match a WGSL/GLSL struct with the same `f32` fields. Under std430 (storage
+/// buffers) such a struct is tightly packed, so the original type can be used as is.
+/// Under std140 (uniform buffers) its alignment and size are rounded up to 16 bytes,
+/// which the unpadded structs of `wgsl!`/`glsl!` don't follow.
+pub fn gpu_layouts(name: &Ident, basis: &Basis) -> TokenStream {
+	let std140 = std140_name(name);
+	let std430 = format_ident!("{}Std430", name);
+	let els = basis_names(basis);
+	let padding = (4 - els.len() % 4) % 4;
+	let std140_doc = format!("`{name}` laid out for a std140 (uniform) buffer");
+	let std430_doc =
+		format!("`{name}` laid out for a std430 (storage) buffer, which is its own layout");
+
+	let maybe_bytemuck = super::impl_bytemuck(&std140, els.len() + padding);
+	let maybe_bevy_reflect = super::maybe_bevy_reflect(false);
+
+	quote! {
+		#[doc = #std140_doc]
+		#[derive(Clone, Copy, PartialEq, Debug)]
+		#maybe_bevy_reflect
+		#[repr(C, align(16))]
+		pub struct #std140 {
+			#(
+				pub #els: f32,
+			)*
+			_padding: [f32; #padding],
+		}
+
+		#[doc = #std430_doc]
+		pub type #std430 = #name;
+
+		impl Default for #std140 {
+			fn default() -> Self {
+				Self::from(#name::default())
+			}
+		}
+
+		impl From<#name> for #std140 {
+			fn from(v: #name) -> Self {
+				Self {
+					#(
+						#els: v.#els,
+					)*
+					_padding: [0.0; #padding],
+				}
+			}
+		}
+
+		impl From<#std140> for #name {
+			fn from(v: #std140) -> Self {
+				Self {
+					#(
+						#els: v.#els,
+					)*
+				}
+			}
+		}
+
+		#maybe_bytemuck
+	}
+}
@@ -3,31 +3,22 @@ use quote::quote;
 
 use super::impl_normalized;
 use crate::{
-	util::{basis_names, element_name, infer, mul_bases, Basis, Sign},
-	MvKind,
+	util::{basis_names, combination_expr, element_name, infer, left_contraction, wedge, Basis},
+	MvKind, Options,
 };
 
 pub fn kvector_methods(
 	gen: &mut Vec<proc_macro2::TokenStream>,
 	canonical_basis: &Basis,
 	kvectors: &[Basis],
+	options: &Options,
 ) {
-	for (k, elements) in kvectors.iter().enumerate() {
+	for &k in &options.grades {
+		let elements = &kvectors[k];
 		let kind = MvKind::KVector(k);
 		let element_names = basis_names(elements);
 		gen.push(quote! {
-			impl std::ops::Mul<#kind> for #kind {
-				type Output = #kind;
-				fn mul(self, rhs: #kind) -> Self::Output {
-					Self::Output {
-						#(
-							#element_names: self.#element_names * rhs.#element_names,
-						)*
-					}
-				}
-			}
-
-			impl std::ops::Mul<f32> for #kind {
+			impl core::ops::Mul<f32> for #kind {
 				type Output = #kind;
 				fn mul(self, rhs: f32) -> Self::Output {
 					Self::Output {
@@ -39,21 +30,130 @@ pub fn kvector_methods(
 			}
 		});
 
-		impl_methods(gen, kind, &elements);
+		impl_methods(gen, kind, elements);
+
+		impl_add(gen, kind, elements);
+		impl_sub(gen, kind, elements);
+		impl_neg(gen, kind, elements);
+		impl_scalar_add(gen, canonical_basis, kind, elements);
+	}
 
-		impl_add(gen, kind, &elements);
-		impl_sub(gen, kind, &elements);
-		impl_neg(gen, kind, &elements);
+	for &(i, j) in &options.wedge {
+		impl_wedge(gen, canonical_basis, i, &kvectors[i], j, &kvectors[j]);
 	}
 
-	let dim = canonical_basis.0.last().unwrap().0.len();
-	for i in 0..kvectors.len() {
-		for j in 0..kvectors.len() {
-			if i + j <= dim {
-				impl_wedge(gen, canonical_basis, i, &kvectors[i], j, &kvectors[j]);
+	impl_v1_methods(gen, kvectors);
+}
+
+/// Everyday vector geometry, from the inner and outer products
+fn impl_v1_methods(gen: &mut Vec<TokenStream>, kvectors: &[Basis]) {
+	let v1 = &kvectors[1];
+	let els = basis_names(v1);
+	let operands = [quote! { self }, quote! { rhs }];
+
+	// a ∧ b, whose norm is the area of the parallelogram spanned by a and b
+	let wedge_terms = wedge(1, v1, 1, v1);
+	let wedge_exprs = wedge_terms
+		.0
+		.values()
+		.map(|sum| combination_expr(Some(sum), &operands))
+		.collect::<Vec<_>>();
+	let wedge_len = wedge_exprs.len();
+
+	gen.push(quote! {
+		impl V1 {
+			/// The inner product, which is the scalar part of the geometric product `self * rhs`
+			pub fn dot(self, rhs: V1) -> f32 {
+				#(
+					self.#els * rhs.#els
+				)+*
+			}
+
+			pub fn norm_squared(self) -> f32 {
+				self.dot(self)
+			}
+
+			pub fn distance(self, rhs: V1) -> f32 {
+				(rhs - self).norm()
+			}
+
+			/// Linear interpolation, from `self` at `t = 0` to `rhs` at `t = 1`
+			pub fn lerp(self, rhs: V1, t: f32) -> Self {
+				self + (rhs - self) * t
+			}
+
+			/// The component of `self` parallel to `rhs`, i.e. `(self · rhs) rhs⁻¹`.
+			///
+			/// NaN when `rhs` is zero, which has no inverse.
+			pub fn project_onto(self, rhs: V1) -> Self {
+				rhs * (self.dot(rhs) / rhs.norm_squared())
+			}
+
+			/// The component of `self` orthogonal to `rhs`, computed as `self` minus its projection
+			/// onto `rhs`, which equals `(self ∧ rhs) rhs⁻¹`.
+			///
+			/// NaN when `rhs` is zero.
+			pub fn reject_from(self, rhs: V1) -> Self {
+				self - self.project_onto(rhs)
+			}
+
+			/// The reflection across the hyperplane orthogonal to `normal`, i.e. the sandwich
+			/// `-normal self normal⁻¹`.
+			///
+			/// NaN when `normal` is zero.
+			pub fn reflect(self, normal: V1) -> Self {
+				self - normal * (2.0 * self.dot(normal) / normal.norm_squared())
+			}
+
+			/// The unsigned angle between `self` and `rhs`, in `[0, π]`.
+			///
+			/// `0.0` when either is zero, as `atan2(0, 0)` is, rather than NaN.
+			pub fn angle_between(self, rhs: V1) -> f32 {
+				let wedge: [f32; #wedge_len] = [#(#wedge_exprs),*];
+				let wedge_norm = gang::math::sqrt(wedge.iter().fold(0.0, |sum, w| sum + w * w));
+				gang::math::atan2(wedge_norm, self.dot(rhs))
 			}
 		}
-	}
+	});
+
+	// there is no plane in 1D
+	let Some(v2) = kvectors.get(2) else {
+		return;
+	};
+	let v2_els = basis_names(v2);
+	let contraction_terms = left_contraction(1, v1, 2, v2);
+	let contraction_exprs = |lhs: TokenStream| {
+		let operands = [lhs, quote! { plane }];
+		v1.0.iter()
+			.map(|e| combination_expr(contraction_terms.0.get(e), &operands))
+			.collect::<Vec<_>>()
+	};
+	let self_contraction = contraction_exprs(quote! { self });
+	let c_contraction = contraction_exprs(quote! { c });
+
+	gen.push(quote! {
+		impl V1 {
+			/// The component of `self` in the plane of the simple bivector `plane`, i.e.
+			/// `(self ⌋ plane) ⌋ plane⁻¹`.
+			///
+			/// NaN when `plane` is zero.
+			pub fn project_onto_plane(self, plane: V2) -> Self {
+				let plane_norm_squared = #(plane.#v2_els * plane.#v2_els)+*;
+				// self ⌋ plane, the vector of the plane orthogonal to the projection
+				let c = V1 {
+					#(
+						#els: #self_contraction,
+					)*
+				};
+				// plane⁻¹ = -plane / |plane|²
+				V1 {
+					#(
+						#els: -(#c_contraction) / plane_norm_squared,
+					)*
+				}
+			}
+		}
+	});
 }
 
 fn impl_wedge(
@@ -64,35 +164,16 @@ fn impl_wedge(
 	rhs_grade: usize,
 	rhs_basis: &Basis,
 ) {
-	let mut combinations = mul_bases(&[&lhs_basis, &rhs_basis]);
-
-	combinations
-		.0
-		.retain(|k, _| k.grade() == lhs_grade + rhs_grade);
+	let combinations = wedge(lhs_grade, lhs_basis, rhs_grade, rhs_basis);
 
 	let output_kind = infer(combinations.0.keys().cloned().collect::<Vec<_>>());
 	let output_basis = output_kind.get_elements(&canonical_basis.0);
 
-	let mut rows = vec![];
-	for term in output_basis {
-		let term_name = element_name(&term);
-		rows.push(quote! { #term_name : });
-		if let Some(sum) = combinations.0.get(&term) {
-			for (i, (sign, terms)) in sum.0.iter().enumerate() {
-				match sign {
-					Sign::Neg => rows.push(quote! {-}),
-					Sign::Pos if i != 0 => rows.push(quote! {+}),
-					_ => {}
-				}
-				let lhs_name = element_name(&terms[0]);
-				let rhs_name = element_name(&terms[1]);
-				rows.push(quote! { self.#lhs_name * rhs.#rhs_name});
-			}
-		} else {
-			rows.push(quote! { 0.0 });
-		}
-		rows.push(quote! {,});
-	}
+	let operands = [quote! { self }, quote! { rhs }];
+	let names = output_basis.iter().map(element_name);
+	let exprs = output_basis
+		.iter()
+		.map(|term| combination_expr(combinations.0.get(term), &operands));
 
 	let lhs_kind = MvKind::KVector(lhs_grade);
 	let rhs_kind = MvKind::KVector(rhs_grade);
@@ -102,39 +183,149 @@ fn impl_wedge(
 			type Output = #output_kind;
 			fn wedge(self, rhs: #rhs_kind) -> Self::Output {
 				Self::Output {
-					#(#rows)*
+					#(
+						#names: #exprs,
+					)*
 				}
 			}
 		}
 	});
 }
 
-fn impl_add(gen: &mut Vec<proc_macro2::TokenStream>, kind: MvKind, basis: &Basis) {
-	let element_names = basis_names(basis);
+/// Arithmetic of the general multivector `Mv`, and the scalar addition of `Rot`
+pub(crate) fn mv_methods(
+	gen: &mut Vec<proc_macro2::TokenStream>,
+	canonical_basis: &Basis,
+	rotor_basis: &Basis,
+) {
+	let kind = MvKind::General;
+	let element_names = basis_names(canonical_basis);
 	gen.push(quote! {
-		impl std::ops::Add<#kind> for #kind {
+		impl core::ops::Mul<f32> for #kind {
 			type Output = #kind;
-			fn add(self, rhs: #kind) -> Self::Output {
+			fn mul(self, rhs: f32) -> Self::Output {
 				Self::Output {
 					#(
-						#element_names: self.#element_names + rhs.#element_names,
+						#element_names: self.#element_names * rhs,
 					)*
 				}
 			}
 		}
+	});
 
-		impl std::ops::Add<f32> for #kind {
-			type Output = #kind;
+	impl_methods(gen, kind, canonical_basis);
+
+	impl_add(gen, kind, canonical_basis);
+	impl_sub(gen, kind, canonical_basis);
+	impl_neg(gen, kind, canonical_basis);
+	impl_scalar_add(gen, canonical_basis, kind, canonical_basis);
+
+	impl_scalar_add(gen, canonical_basis, MvKind::Rotor, rotor_basis);
+}
+
+/// Sum with a scalar, which is the grade 0 part of the output.
+///
+/// Scalars are even, so the sum stays in `Rot` for the even grades, and is a general `Mv` for
+/// the odd ones.
+fn impl_scalar_add(
+	gen: &mut Vec<proc_macro2::TokenStream>,
+	canonical_basis: &Basis,
+	kind: MvKind,
+	basis: &Basis,
+) {
+	let output_kind = match kind {
+		MvKind::KVector(0) => kind,
+		MvKind::KVector(k) if k % 2 == 0 => MvKind::Rotor,
+		MvKind::KVector(_) => MvKind::General,
+		MvKind::Rotor | MvKind::General => kind,
+	};
+	let output_basis = output_kind.get_elements(&canonical_basis.0);
+
+	// `sign` is the sign of `rhs` alone, in the output fields `self` doesn't have
+	let sum = |op: TokenStream, sign: TokenStream| {
+		output_basis
+			.iter()
+			.map(|e| {
+				let name = element_name(e);
+				match (basis.0.contains(e), e.grade() == 0) {
+					(true, true) => quote! { #name: self.#name #op rhs },
+					(true, false) => quote! { #name: self.#name },
+					(false, true) => quote! { #name: #sign rhs },
+					(false, false) => quote! { #name: 0.0 },
+				}
+			})
+			.collect::<Vec<_>>()
+	};
+	let add_fields = sum(quote! { + }, TokenStream::new());
+	let sub_fields = sum(quote! { - }, quote! { - });
+
+	gen.push(quote! {
+		impl core::ops::Add<f32> for #kind {
+			type Output = #output_kind;
 			fn add(self, rhs: f32) -> Self::Output {
+				#output_kind {
+					#(#add_fields,)*
+				}
+			}
+		}
+
+		impl core::ops::Add<#kind> for f32 {
+			type Output = #output_kind;
+			fn add(self, rhs: #kind) -> Self::Output {
+				rhs + self
+			}
+		}
+
+		impl core::ops::Sub<f32> for #kind {
+			type Output = #output_kind;
+			fn sub(self, rhs: f32) -> Self::Output {
+				#output_kind {
+					#(#sub_fields,)*
+				}
+			}
+		}
+
+		impl core::ops::Sub<#kind> for f32 {
+			type Output = #output_kind;
+			fn sub(self, rhs: #kind) -> Self::Output {
+				rhs * -1.0 + self
+			}
+		}
+	});
+
+	if output_basis.len() == basis.0.len() {
+		let scalar = element_name(&output_basis[0]);
+		gen.push(quote! {
+			impl core::ops::AddAssign<f32> for #kind {
+				fn add_assign(&mut self, rhs: f32) {
+					self.#scalar += rhs;
+				}
+			}
+
+			impl core::ops::SubAssign<f32> for #kind {
+				fn sub_assign(&mut self, rhs: f32) {
+					self.#scalar -= rhs;
+				}
+			}
+		});
+	}
+}
+
+fn impl_add(gen: &mut Vec<proc_macro2::TokenStream>, kind: MvKind, basis: &Basis) {
+	let element_names = basis_names(basis);
+	gen.push(quote! {
+		impl core::ops::Add<#kind> for #kind {
+			type Output = #kind;
+			fn add(self, rhs: #kind) -> Self::Output {
 				Self::Output {
 					#(
-						#element_names: self.#element_names + rhs,
+						#element_names: self.#element_names + rhs.#element_names,
 					)*
 				}
 			}
 		}
 
-		impl std::ops::AddAssign<#kind> for #kind {
+		impl core::ops::AddAssign<#kind> for #kind {
 			fn add_assign(&mut self, rhs: #kind) {
 				#(
 					self.#element_names += rhs.#element_names;
@@ -142,11 +333,14 @@ fn impl_add(gen: &mut Vec<proc_macro2::TokenStream>, kind: MvKind, basis: &Basis
 			}
 		}
 
-		impl std::ops::AddAssign<f32> for #kind {
-			fn add_assign(&mut self, rhs: f32) {
-				#(
-					self.#element_names += rhs;
-				)*
+		impl #kind {
+			/// Adds `rhs` to every component, unlike `+ f32` which only changes the scalar part
+			pub fn add_scalar_each(self, rhs: f32) -> Self {
+				Self {
+					#(
+						#element_names: self.#element_names + rhs,
+					)*
+				}
 			}
 		}
 	});
@@ -155,7 +349,7 @@ fn impl_add(gen: &mut Vec<proc_macro2::TokenStream>, kind: MvKind, basis: &Basis
 fn impl_sub(gen: &mut Vec<proc_macro2::TokenStream>, kind: MvKind, basis: &Basis) {
 	let element_names = basis_names(basis);
 	gen.push(quote! {
-		impl std::ops::Sub<#kind> for #kind {
+		impl core::ops::Sub<#kind> for #kind {
 			type Output = #kind;
 			fn sub(self, rhs: #kind) -> Self::Output {
 				Self::Output {
@@ -166,18 +360,7 @@ fn impl_sub(gen: &mut Vec<proc_macro2::TokenStream>, kind: MvKind, basis: &Basis
 			}
 		}
 
-		impl std::ops::Sub<f32> for #kind {
-			type Output = #kind;
-			fn sub(self, rhs: f32) -> Self::Output {
-				Self::Output {
-					#(
-						#element_names: self.#element_names - rhs,
-					)*
-				}
-			}
-		}
-
-		impl std::ops::SubAssign<#kind> for #kind {
+		impl core::ops::SubAssign<#kind> for #kind {
 			fn sub_assign(&mut self, rhs: #kind) {
 				#(
 					self.#element_names -= rhs.#element_names;
@@ -185,11 +368,14 @@ fn impl_sub(gen: &mut Vec<proc_macro2::TokenStream>, kind: MvKind, basis: &Basis
 			}
 		}
 
-		impl std::ops::SubAssign<f32> for #kind {
-			fn sub_assign(&mut self, rhs: f32) {
-				#(
-					self.#element_names -= rhs;
-				)*
+		impl #kind {
+			/// Subtracts `rhs` from every component, unlike `- f32` which only changes the scalar part
+			pub fn sub_scalar_each(self, rhs: f32) -> Self {
+				Self {
+					#(
+						#element_names: self.#element_names - rhs,
+					)*
+				}
 			}
 		}
 	});
@@ -198,7 +384,7 @@ fn impl_sub(gen: &mut Vec<proc_macro2::TokenStream>, kind: MvKind, basis: &Basis
 fn impl_neg(gen: &mut Vec<proc_macro2::TokenStream>, kind: MvKind, basis: &Basis) {
 	let element_names = basis_names(basis);
 	gen.push(quote! {
-		impl std::ops::Neg for #kind {
+		impl core::ops::Neg for #kind {
 			type Output = #kind;
 			fn neg(self) -> Self::Output {
 				Self::Output {
@@ -214,6 +400,7 @@ fn impl_neg(gen: &mut Vec<proc_macro2::TokenStream>, kind: MvKind, basis: &Basis
 fn impl_methods(gen: &mut Vec<proc_macro2::TokenStream>, kind: MvKind, basis: &Basis) {
 	let methods = vec![
 		impl_sign(basis),
+		impl_elementwise(basis),
 		impl_cmp(basis),
 		impl_abs_diff_eq(basis),
 		impl_rounding(basis),
@@ -235,7 +422,39 @@ fn impl_sign(basis: &Basis) -> TokenStream {
 		pub fn abs(self) -> Self {
 			Self {
 				#(
-					#els: self.#els.abs(),
+					#els: gang::math::abs(self.#els),
+				)*
+			}
+		}
+	}
+}
+
+/// Component-wise operations, since `*` is the geometric product
+fn impl_elementwise(basis: &Basis) -> TokenStream {
+	let els = basis_names(basis);
+	quote! {
+		#[doc(alias = "hadamard")]
+		pub fn mul_elementwise(self, rhs: Self) -> Self {
+			Self {
+				#(
+					#els: self.#els * rhs.#els,
+				)*
+			}
+		}
+
+		pub fn div_elementwise(self, rhs: Self) -> Self {
+			Self {
+				#(
+					#els: self.#els / rhs.#els,
+				)*
+			}
+		}
+
+		/// The reciprocal of every component, not the inverse for the geometric product
+		pub fn recip(self) -> Self {
+			Self {
+				#(
+					#els: self.#els.recip(),
 				)*
 			}
 		}
@@ -263,12 +482,12 @@ fn impl_cmp(basis: &Basis) -> TokenStream {
 	}
 }
 
-fn impl_abs_diff_eq(basis: &Basis) -> TokenStream {
+pub(crate) fn impl_abs_diff_eq(basis: &Basis) -> TokenStream {
 	let els = basis_names(basis);
 	quote! {
 		pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
 			#(
-				(self.#els - rhs.#els).abs() < max_abs_diff
+				gang::math::abs(self.#els - rhs.#els) < max_abs_diff
 			)&&*
 		}
 	}
@@ -280,21 +499,21 @@ fn impl_rounding(basis: &Basis) -> TokenStream {
 		pub fn floor(self) -> Self {
 			Self {
 				#(
-					#els: self.#els.floor(),
+					#els: gang::math::floor(self.#els),
 				)*
 			}
 		}
 		pub fn round(self) -> Self {
 			Self {
 				#(
-					#els: self.#els.round(),
+					#els: gang::math::round(self.#els),
 				)*
 			}
 		}
 		pub fn ceil(self) -> Self {
 			Self {
 				#(
-					#els: self.#els.ceil(),
+					#els: gang::math::ceil(self.#els),
 				)*
 			}
 		}
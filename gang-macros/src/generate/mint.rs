@@ -0,0 +1,100 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+	util::{basis_names, Basis},
+	Options,
+};
+
+/// Conversions to and from `mint` types, for the dimensions that mint covers
+pub(crate) fn impl_mint(gen: &mut Vec<TokenStream>, v1_basis: &Basis, options: &Options) {
+	if !cfg!(feature = "mint") {
+		return;
+	}
+
+	let dim = v1_basis.0.len();
+	if !(2..=4).contains(&dim) {
+		return;
+	}
+
+	let els = basis_names(v1_basis);
+	let fields = ["x", "y", "z", "w"][..dim]
+		.iter()
+		.map(|f| format_ident!("{f}"))
+		.collect::<Vec<_>>();
+	let vector = format_ident!("Vector{dim}");
+	let matrix = format_ident!("ColumnMatrix{dim}");
+
+	for scalar in [quote!(f32), quote!(f64)] {
+		gen.push(quote! {
+			impl From<V1> for gang::__private::mint::#vector<#scalar> {
+				fn from(v: V1) -> Self {
+					Self {
+						#(
+							#fields: v.#els as #scalar,
+						)*
+					}
+				}
+			}
+
+			impl From<gang::__private::mint::#vector<#scalar>> for V1 {
+				fn from(v: gang::__private::mint::#vector<#scalar>) -> Self {
+					Self {
+						#(
+							#els: v.#fields as f32,
+						)*
+					}
+				}
+			}
+		});
+	}
+
+	if options.rotate.contains(&1) {
+		gen.push(quote! {
+			impl From<Rot> for gang::__private::mint::#matrix<f32> {
+				fn from(r: Rot) -> Self {
+					Self::from(r.v1_rotation_matrix())
+				}
+			}
+		});
+	}
+
+	if options.has_from_matrix() {
+		gen.push(quote! {
+			impl From<gang::__private::mint::#matrix<f32>> for Rot {
+				fn from(m: gang::__private::mint::#matrix<f32>) -> Self {
+					Self::from_v1_rotation_matrix(m.into())
+				}
+			}
+		});
+	}
+
+	if dim == 3 {
+		gen.push(quote! {
+			/// The quaternion `w + xi + yj + zk` corresponds to the rotor `w - z e01 + y e02 - x e12`
+			impl From<Rot> for gang::__private::mint::Quaternion<f32> {
+				fn from(r: Rot) -> Self {
+					Self {
+						s: r.e,
+						v: gang::__private::mint::Vector3 {
+							x: -r.e12,
+							y: r.e02,
+							z: -r.e01,
+						},
+					}
+				}
+			}
+
+			impl From<gang::__private::mint::Quaternion<f32>> for Rot {
+				fn from(q: gang::__private::mint::Quaternion<f32>) -> Self {
+					Self {
+						e: q.s,
+						e01: -q.v.z,
+						e02: q.v.y,
+						e12: -q.v.x,
+					}
+				}
+			}
+		});
+	}
+}
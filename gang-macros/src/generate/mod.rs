@@ -3,10 +3,36 @@ use quote::quote;
 
 use crate::util::{basis_names, element_name, element_name_upper, Basis};
 
+mod algebra;
+mod approx;
+mod bevy_reflect;
+mod bytemuck;
+mod glam;
+mod gpu;
 mod kvector;
+mod mint;
+mod nalgebra;
+mod proptest;
+mod rand;
 mod rot;
-pub(crate) use kvector::kvector_methods;
-pub(crate) use rot::{impl_rotate, impl_to_matrix, rotor_methods};
+mod serde;
+mod wide;
+pub(crate) use algebra::impl_algebra;
+pub(crate) use approx::{impl_approx, impl_rotor_approx};
+pub(crate) use bevy_reflect::{impl_bevy_reflect, maybe_bevy_reflect};
+pub(crate) use bytemuck::impl_bytemuck;
+pub(crate) use glam::impl_glam;
+pub(crate) use gpu::{gpu_layouts, std140_name};
+pub(crate) use kvector::{kvector_methods, mv_methods};
+pub(crate) use mint::impl_mint;
+pub(crate) use nalgebra::impl_nalgebra;
+pub(crate) use proptest::impl_proptest;
+pub(crate) use rand::{impl_rand, impl_rand_wide};
+pub(crate) use rot::{
+	impl_from_matrix, impl_rotate, impl_rotate_batch, impl_to_matrix, rotor_methods,
+};
+pub(crate) use serde::{impl_serde_seq, maybe_serde};
+pub(crate) use wide::{wide_name, wide_types, WIDTHS};
 
 pub fn constants(basis: &Basis) -> proc_macro2::TokenStream {
 	let mut constants_tokens = proc_macro2::TokenStream::new();
@@ -38,11 +64,11 @@ fn impl_normalized(basis: &Basis) -> TokenStream {
 	let els = basis_names(basis);
 	quote! {
 		pub fn norm(self) -> f32 {
-			(
+			gang::math::sqrt(
 				#(
 					self.#els*self.#els
 				)+*
-			).sqrt()
+			)
 		}
 
 		pub fn normalize(self) -> Self {
@@ -0,0 +1,78 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{util::Basis, MvKind, Options};
+
+/// Conversions to and from `nalgebra` types
+pub(crate) fn impl_nalgebra(gen: &mut Vec<TokenStream>, kvectors: &[Basis], options: &Options) {
+	if !cfg!(feature = "nalgebra") {
+		return;
+	}
+
+	for &k in &options.grades {
+		let basis = &kvectors[k];
+		let kind = MvKind::KVector(k);
+		let len = basis.0.len();
+		gen.push(quote! {
+			impl From<#kind> for gang::__private::nalgebra::SVector<f32, #len> {
+				fn from(v: #kind) -> Self {
+					Self::from(v.to_array())
+				}
+			}
+
+			impl From<gang::__private::nalgebra::SVector<f32, #len>> for #kind {
+				fn from(v: gang::__private::nalgebra::SVector<f32, #len>) -> Self {
+					Self::from_array(v.into())
+				}
+			}
+		});
+	}
+
+	let dim = kvectors[1].0.len();
+	let matrix_len = dim * dim;
+	if options.rotate.contains(&1) {
+		gen.push(quote! {
+			impl From<Rot> for gang::__private::nalgebra::Rotation<f32, #dim> {
+				fn from(r: Rot) -> Self {
+					Self::from_matrix_unchecked(gang::__private::nalgebra::SMatrix::from_column_slice(
+						&r.v1_rotation_matrix(),
+					))
+				}
+			}
+		});
+	}
+	if options.has_from_matrix() {
+		gen.push(quote! {
+			impl From<gang::__private::nalgebra::Rotation<f32, #dim>> for Rot {
+				fn from(r: gang::__private::nalgebra::Rotation<f32, #dim>) -> Self {
+					let mut m = [0.0; #matrix_len];
+					m.copy_from_slice(r.matrix().as_slice());
+					Self::from_v1_rotation_matrix(m)
+				}
+			}
+		});
+	}
+
+	if dim == 3 {
+		gen.push(quote! {
+			/// The rotor maps to the quaternion `w = e, i = -e12, j = e02, k = -e01`, normalized as
+			/// the rotor may have drifted from unit length
+			impl From<Rot> for gang::__private::nalgebra::UnitQuaternion<f32> {
+				fn from(r: Rot) -> Self {
+					Self::from_quaternion(gang::__private::nalgebra::Quaternion::new(r.e, -r.e12, r.e02, -r.e01))
+				}
+			}
+
+			impl From<gang::__private::nalgebra::UnitQuaternion<f32>> for Rot {
+				fn from(q: gang::__private::nalgebra::UnitQuaternion<f32>) -> Self {
+					Self {
+						e: q.w,
+						e01: -q.k,
+						e02: q.j,
+						e12: -q.i,
+					}
+				}
+			}
+		});
+	}
+}
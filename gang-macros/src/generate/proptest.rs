@@ -0,0 +1,184 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+	util::{element_name_upper, Basis, Element},
+	MvKind, Options,
+};
+
+/// `Arbitrary` for every type, the `strategy` module for unit elements,
+/// and `gang::testing::Checkable`
+pub(crate) fn impl_proptest(gen: &mut Vec<TokenStream>, kvectors: &[Basis], options: &Options) {
+	if !cfg!(feature = "proptest") {
+		return;
+	}
+
+	let dim = kvectors[1].0.len();
+	let mut kinds = vec![];
+	for &k in &options.grades {
+		let basis = &kvectors[k];
+		let kind = MvKind::KVector(k);
+		let len = basis.0.len();
+		gen.push(quote! {
+			impl gang::testing::Checkable for #kind {
+				fn norm(self) -> f32 {
+					#kind::norm(self)
+				}
+
+				fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+					#kind::abs_diff_eq(self, rhs, max_abs_diff)
+				}
+			}
+
+			impl gang::__private::proptest::arbitrary::Arbitrary for #kind {
+				type Parameters = ();
+				type Strategy = gang::__private::proptest::strategy::BoxedStrategy<Self>;
+
+				fn arbitrary_with(_: ()) -> Self::Strategy {
+					gang::__private::proptest::strategy::Strategy::boxed(gang::__private::proptest::strategy::Strategy::prop_map(
+						gang::__private::proptest::array::uniform::<_, #len>(strategy::COMPONENT),
+						Self::from_array,
+					))
+				}
+			}
+		});
+		kinds.push(kind);
+	}
+
+	let mv_len = kvectors.iter().map(|basis| basis.0.len()).sum::<usize>();
+	gen.push(quote! {
+		impl gang::testing::Checkable for Mv {
+			fn norm(self) -> f32 {
+				Mv::norm(self)
+			}
+
+			fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+				Mv::abs_diff_eq(self, rhs, max_abs_diff)
+			}
+		}
+
+		impl gang::__private::proptest::arbitrary::Arbitrary for Mv {
+			type Parameters = ();
+			type Strategy = gang::__private::proptest::strategy::BoxedStrategy<Self>;
+
+			fn arbitrary_with(_: ()) -> Self::Strategy {
+				gang::__private::proptest::strategy::Strategy::boxed(gang::__private::proptest::strategy::Strategy::prop_map(
+					gang::__private::proptest::array::uniform::<_, #mv_len>(strategy::COMPONENT),
+					Self::from_array,
+				))
+			}
+		}
+	});
+
+	// unit rotors are composed from plane rotations, with the geometric product
+	let unit_rot = if options.has_rot_mul() {
+		kinds.push(MvKind::Rotor);
+		let angles = (0..dim)
+			.flat_map(|j| (j + 1..dim).map(move |i| (j, i)))
+			.map(|(j, i)| element_name_upper(&Element::from_indices([j as u32, i as u32])))
+			.collect::<Vec<_>>();
+		let nb_angles = angles.len();
+		let indices = 0..nb_angles;
+		quote! {
+			/// Unit rotors, as products of rotations in every coordinate plane
+			pub fn unit_rot() -> impl gang::__private::proptest::strategy::Strategy<Value = Rot> {
+				gang::__private::proptest::strategy::Strategy::prop_map(
+					gang::__private::proptest::array::uniform::<_, #nb_angles>(-core::f32::consts::PI..core::f32::consts::PI),
+					#[allow(unused_variables)]
+					|angles| Rot::IDENTITY #(* Rot::from_v2_angle(V2::#angles, angles[#indices]))*,
+				)
+			}
+		}
+	} else {
+		TokenStream::new()
+	};
+
+	gen.push(quote! {
+		/// Strategies for the elements that `Arbitrary` doesn't cover
+		pub mod strategy {
+			use super::*;
+
+			/// Range of the components of arbitrary k-vectors, so that products stay finite
+			pub const COMPONENT: core::ops::Range<f32> = -100.0..100.0;
+
+			/// Unit vectors, uniformly distributed
+			pub fn unit_v1() -> impl gang::__private::proptest::strategy::Strategy<Value = V1> {
+				// normally distributed components, from the Box-Muller transform, give an
+				// isotropic distribution
+				gang::__private::proptest::strategy::Strategy::prop_filter_map(
+					gang::__private::proptest::array::uniform::<_, #dim>((f32::MIN_POSITIVE..=1.0f32, 0.0f32..1.0)),
+					"vector too close to zero",
+					|a| {
+						let v = V1::from_array(a.map(|(u, v)| gang::math::box_muller(u, v)));
+						let norm = v.norm();
+						(norm > 1e-6).then(|| v * norm.recip())
+					},
+				)
+			}
+
+			#unit_rot
+		}
+
+		impl gang::testing::Checkable for Rot {
+			fn norm(self) -> f32 {
+				Rot::norm(self)
+			}
+
+			fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+				Rot::abs_diff_eq(self, rhs, max_abs_diff)
+			}
+		}
+	});
+
+	if options.has_rot_mul() {
+		gen.push(quote! {
+			/// Only unit rotors, which are the ones representing rotations
+			impl gang::__private::proptest::arbitrary::Arbitrary for Rot {
+				type Parameters = ();
+				type Strategy = gang::__private::proptest::strategy::BoxedStrategy<Self>;
+
+				fn arbitrary_with(_: ()) -> Self::Strategy {
+					gang::__private::proptest::strategy::Strategy::boxed(strategy::unit_rot())
+				}
+			}
+		});
+	}
+
+	if !options.layouts {
+		return;
+	}
+	kinds.push(MvKind::General);
+	for kind in kinds {
+		let name = format_ident!("{}", quote!(#kind).to_string());
+		let std140 = super::std140_name(&name);
+		gen.push(quote! {
+			impl gang::__private::proptest::arbitrary::Arbitrary for #std140 {
+				type Parameters = ();
+				type Strategy = gang::__private::proptest::strategy::BoxedStrategy<Self>;
+
+				fn arbitrary_with(_: ()) -> Self::Strategy {
+					gang::__private::proptest::strategy::Strategy::boxed(gang::__private::proptest::strategy::Strategy::prop_map(
+						gang::__private::proptest::arbitrary::any::<#kind>(),
+						Self::from,
+					))
+				}
+			}
+		});
+		for width in super::WIDTHS {
+			let wide = super::wide_name(kind, width);
+			gen.push(quote! {
+				impl gang::__private::proptest::arbitrary::Arbitrary for #wide {
+					type Parameters = ();
+					type Strategy = gang::__private::proptest::strategy::BoxedStrategy<Self>;
+
+					fn arbitrary_with(_: ()) -> Self::Strategy {
+						gang::__private::proptest::strategy::Strategy::boxed(gang::__private::proptest::strategy::Strategy::prop_map(
+							gang::__private::proptest::arbitrary::any::<[#kind; #width]>(),
+							Self::from_lanes,
+						))
+					}
+				}
+			});
+		}
+	}
+}
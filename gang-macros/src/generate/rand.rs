@@ -0,0 +1,145 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+	util::{basis_names, Basis},
+	MvKind, Options,
+};
+
+/// Distributions of `gang::distr`, and `StandardUniform` for every type
+pub(crate) fn impl_rand(gen: &mut Vec<TokenStream>, kvectors: &[Basis], options: &Options) {
+	if !cfg!(feature = "rand") {
+		return;
+	}
+
+	for &k in &options.grades {
+		let basis = &kvectors[k];
+		let kind = MvKind::KVector(k);
+		let els = basis_names(basis);
+		gen.push(quote! {
+			impl gang::__private::rand::distr::Distribution<#kind> for gang::__private::rand::distr::StandardUniform {
+				fn sample<R: gang::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> #kind {
+					#kind {
+						#(
+							#els: gang::__private::rand::Rng::random(rng),
+						)*
+					}
+				}
+			}
+		});
+	}
+
+	let mv_els = kvectors.iter().flat_map(basis_names).collect::<Vec<_>>();
+	gen.push(quote! {
+		impl gang::__private::rand::distr::Distribution<Mv> for gang::__private::rand::distr::StandardUniform {
+			fn sample<R: gang::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> Mv {
+				Mv {
+					#(
+						#mv_els: gang::__private::rand::Rng::random(rng),
+					)*
+				}
+			}
+		}
+	});
+
+	let dim = kvectors[1].0.len();
+	let els = basis_names(&kvectors[1]);
+	gen.push(quote! {
+		impl gang::__private::rand::distr::Distribution<V1> for gang::distr::UnitSphere {
+			fn sample<R: gang::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> V1 {
+				// normally distributed components give an isotropic distribution
+				loop {
+					let v = V1 {
+						#(
+							#els: gang::distr::standard_normal(rng),
+						)*
+					};
+					let norm = v.norm();
+					if norm > 0.0 {
+						return v * norm.recip();
+					}
+				}
+			}
+		}
+	});
+
+	if options.wedge.contains(&(1, 1)) {
+		gen.push(quote! {
+			impl gang::__private::rand::distr::Distribution<V2> for gang::distr::UnitSimpleBivector {
+				fn sample<R: gang::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> V2 {
+					// the plane spanned by two independent directions is uniformly distributed
+					loop {
+						let a: V1 = gang::__private::rand::Rng::sample(rng, gang::distr::UnitSphere);
+						let b: V1 = gang::__private::rand::Rng::sample(rng, gang::distr::UnitSphere);
+						let v = gang::Wedge::wedge(a, b);
+						let norm = v.norm();
+						if norm > 1e-3 {
+							return v * norm.recip();
+						}
+					}
+				}
+			}
+		});
+	}
+
+	if !options.has_from_matrix() {
+		return;
+	}
+
+	let matrix_len = dim * dim;
+	gen.push(quote! {
+		impl gang::__private::rand::distr::Distribution<Rot> for gang::__private::rand::distr::StandardUniform {
+			fn sample<R: gang::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> Rot {
+				gang::__private::rand::Rng::sample(rng, gang::distr::UniformRotor)
+			}
+		}
+
+		impl gang::__private::rand::distr::Distribution<Rot> for gang::distr::UniformRotor {
+			fn sample<R: gang::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> Rot {
+				// Gram-Schmidt on normally distributed columns gives a uniformly distributed
+				// orthogonal matrix. When it is a reflection, `from_v1_rotation_matrix` ends
+				// with -1 as the last diagonal entry, and gives the rotation with the last
+				// column flipped instead, which is still uniformly distributed.
+				let mut m = [0.0; #matrix_len];
+				for j in 0..#dim {
+					loop {
+						let mut col: [f32; #dim] = core::array::from_fn(|_| gang::distr::standard_normal(rng));
+						for k in 0..j {
+							let dot = (0..#dim).map(|i| col[i] * m[i + k * #dim]).sum::<f32>();
+							for i in 0..#dim {
+								col[i] -= dot * m[i + k * #dim];
+							}
+						}
+						let norm = gang::math::sqrt(col.iter().map(|x| x * x).sum::<f32>());
+						if norm > 1e-3 {
+							for i in 0..#dim {
+								m[i + j * #dim] = col[i] / norm;
+							}
+							break;
+						}
+					}
+				}
+				Rot::from_v1_rotation_matrix(m)
+			}
+		}
+	});
+}
+
+/// `StandardUniform` for a structure-of-arrays type, sampling every lane
+pub(crate) fn impl_rand_wide(
+	name: &proc_macro2::Ident,
+	kind: MvKind,
+	options: &Options,
+) -> TokenStream {
+	if !cfg!(feature = "rand") || matches!(kind, MvKind::Rotor) && !options.has_from_matrix() {
+		return TokenStream::new();
+	}
+
+	quote! {
+		impl gang::__private::rand::distr::Distribution<#name> for gang::__private::rand::distr::StandardUniform {
+			fn sample<R: gang::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> #name {
+				#name::from_lanes(core::array::from_fn(|_| gang::__private::rand::Rng::sample::<#kind, _>(rng, gang::__private::rand::distr::StandardUniform)))
+			}
+		}
+	}
+}
@@ -1,13 +1,18 @@
 use quote::{quote, ToTokens};
 
-use crate::util::{
-	basis_names, element_name, impl_mul_bases, Basis, LinearCombination, LinearCombinations, Sign,
+use crate::{
+	util::{
+		basis_names, combination_expr, element_name_upper, sandwich, Basis, Element,
+		LinearCombination,
+	},
+	MvKind,
 };
 
 pub(crate) fn rotor_methods(gen: &mut Vec<proc_macro2::TokenStream>, rotor_basis: &Basis) {
 	let els = basis_names(rotor_basis);
+	let abs_diff_eq = super::kvector::impl_abs_diff_eq(rotor_basis);
 	gen.push(quote! {
-		impl std::ops::Mul<f32> for Rot {
+		impl core::ops::Mul<f32> for Rot {
 			type Output = Self;
 			fn mul(self, rhs: f32) -> Self::Output {
 				Self::Output {
@@ -20,17 +25,40 @@ pub(crate) fn rotor_methods(gen: &mut Vec<proc_macro2::TokenStream>, rotor_basis
 
 		impl Rot {
 			pub fn norm(self) -> f32 {
-				(
+				gang::math::sqrt(
 					#(
 						self.#els*self.#els
 					)+*
-				).sqrt()
+				)
 			}
 
 			pub fn normalize(self) -> Self {
 				let norm = self.norm();
 				self * norm.recip()
 			}
+
+			#abs_diff_eq
+
+			/// Like `abs_diff_eq`, but also true if `rhs` is close to `-self`, which is the same rotation
+			pub fn abs_diff_eq_up_to_sign(self, rhs: Self, max_abs_diff: f32) -> bool {
+				self.abs_diff_eq(rhs, max_abs_diff) || self.abs_diff_eq(rhs * -1.0, max_abs_diff)
+			}
+
+			/// Rotates every element in place, deriving the rotation matrix of their grade only once
+			pub fn rotate_slice<T>(&self, rhs: &mut [T])
+			where
+				Self: gang::RotateBatch<T>,
+			{
+				gang::RotateBatch::rotate_slice(self, rhs)
+			}
+
+			/// Rotates every element, deriving the rotation matrix of their grade only once
+			pub fn rotate_iter<T, I: IntoIterator<Item = T>>(&self, rhs: I) -> impl Iterator<Item = T> + use<'_, T, I>
+			where
+				Self: gang::RotateBatch<T>,
+			{
+				gang::RotateBatch::rotate_iter(self, rhs)
+			}
 		}
 	});
 }
@@ -42,43 +70,7 @@ pub(crate) fn impl_to_matrix(
 ) {
 	let kind = crate::util::infer(rhs_basis.0.clone());
 
-	let mut result = LinearCombinations::one();
-
-	result = impl_mul_bases(
-		&result,
-		&rotor_basis
-			.0
-			.iter()
-			.map(|r| (Sign::Pos, r.clone()))
-			.collect::<Vec<_>>(),
-	);
-
-	result = impl_mul_bases(
-		&result,
-		&rhs_basis
-			.0
-			.iter()
-			.map(|r| (Sign::Pos, r.clone()))
-			.collect::<Vec<_>>(),
-	);
-
-	result = impl_mul_bases(
-		&result,
-		&rotor_basis
-			.0
-			.iter()
-			.map(|r| {
-				(
-					match r.grade() / 2 % 2 {
-						0 => Sign::Pos,
-						1 => Sign::Neg,
-						_ => unreachable!("maths broke"),
-					},
-					r.clone(),
-				)
-			})
-			.collect::<Vec<_>>(),
-	);
+	let result = sandwich(rotor_basis, rhs_basis);
 
 	let nb_els = rhs_basis.0.len();
 
@@ -88,43 +80,119 @@ pub(crate) fn impl_to_matrix(
 		let comb = &result.0[element];
 		for term in &comb.0 {
 			let j = rhs_basis.0.iter().position(|el| el == &term.1[1]).unwrap();
-			// println!("filling: {:?} {:?}", i, j);
 			matrix[i + j * nb_els] // column-major
 				.0
-				.push((term.0, vec![term.1[0].clone(), term.1[2].clone()]));
+				.push((term.0, vec![term.1[0], term.1[2]]));
 		}
 	}
 
-	// println!("\n\nhello again!!!");
+	let operands = [quote! { self }, quote! { self }];
+	let els = matrix
+		.iter()
+		.map(|sum| combination_expr(Some(sum), &operands));
 
-	let mut els = vec![];
-	for sum in matrix {
-		for (i, (sign, factors)) in sum.0.iter().enumerate() {
-			match sign {
-				Sign::Neg => els.push(quote! {-}),
-				Sign::Pos if i != 0 => els.push(quote! {+}),
-				_ => {}
+	let method_name = matrix_method_name(kind);
+	let matrix_len = nb_els * nb_els;
+	gen.push(quote! {
+		impl Rot {
+			/// Column-major matrix of the rotation
+			pub fn #method_name(self) -> [f32; #matrix_len] {
+				[
+					#(#els),*
+				]
 			}
-			let rot_factor = element_name(&factors[0]);
-			let rotd_factor = element_name(&factors[1]);
-			els.push(quote! { self.#rot_factor*self.#rotd_factor });
 		}
-		els.push(quote! { , });
+	});
+}
+
+/// Recovers a rotor from a vector rotation matrix, by eliminating its
+/// subdiagonal with plane rotations (Givens rotations)
+pub(crate) fn impl_from_matrix(gen: &mut Vec<proc_macro2::TokenStream>, v1_basis: &Basis) {
+	let n = v1_basis.0.len();
+	let matrix_len = n * n;
+
+	let mut steps = vec![];
+	for j in 0..n {
+		for i in (j + 1..n).rev() {
+			let plane = element_name_upper(&Element::from_indices([j as u32, i as u32]));
+			let (jj, ij) = (j + j * n, i + j * n);
+			steps.push(quote! {
+				let theta = gang::math::atan2(m[#ij], m[#jj]);
+				let (s, c) = gang::math::sin_cos(theta);
+				for k in #j..#n {
+					let (p, q) = (m[#j + k * #n], m[#i + k * #n]);
+					m[#j + k * #n] = c * p + s * q;
+					m[#i + k * #n] = c * q - s * p;
+				}
+				rot = rot * Self::from_v2_angle(V2::#plane, theta);
+			});
+		}
 	}
 
-	let method_name = proc_macro2::Ident::new(
+	gen.push(quote! {
+		impl Rot {
+			/// Rotor of a column-major rotation matrix, the inverse of `v1_rotation_matrix`
+			pub fn from_v1_rotation_matrix(mut m: [f32; #matrix_len]) -> Self {
+				let mut rot = Self::IDENTITY;
+				#(
+					{
+						#steps
+					}
+				)*
+				rot
+			}
+		}
+	});
+}
+
+fn matrix_method_name(kind: MvKind) -> proc_macro2::Ident {
+	proc_macro2::Ident::new(
 		&format!(
 			"{}_rotation_matrix",
 			kind.into_token_stream().to_string().to_lowercase()
 		),
 		proc_macro2::Span::call_site(),
-	);
+	)
+}
+
+/// Rotates many elements of the same grade through a single rotation matrix
+pub(crate) fn impl_rotate_batch(gen: &mut Vec<proc_macro2::TokenStream>, rhs_basis: &Basis) {
+	let kind = crate::util::infer(rhs_basis.0.clone());
+	let method_name = matrix_method_name(kind);
+	let els = basis_names(rhs_basis);
+	let nb_els = els.len();
+
+	// the matrix is column-major
+	let rows = (0..nb_els)
+		.map(|i| {
+			let terms = els.iter().enumerate().map(|(j, el)| {
+				let idx = i + j * nb_els;
+				quote! { m[#idx]*rhs.#el }
+			});
+			quote! { #(#terms)+* }
+		})
+		.collect::<Vec<_>>();
+
 	gen.push(quote! {
-		impl Rot {
-			pub fn #method_name(self) -> Vec<f32> {
-				vec![
-					#(#els)*
-				]
+		impl gang::RotateBatch<#kind> for Rot {
+			fn rotate_slice(&self, rhs: &mut [#kind]) {
+				let m = self.#method_name();
+				for rhs in rhs {
+					*rhs = #kind {
+						#(
+							#els: #rows,
+						)*
+					};
+				}
+			}
+
+			fn rotate_iter<I: IntoIterator<Item = #kind>>(&self, rhs: I) -> impl Iterator<Item = #kind> {
+				let m = self.#method_name();
+				rhs.into_iter().map(move |rhs| #kind {
+					#(
+						#els: #rows,
+					)*
+				})
 			}
 		}
 	});
@@ -136,74 +204,26 @@ pub(crate) fn impl_rotate(
 	rhs_basis: &Basis,
 ) {
 	let kind = crate::util::infer(rhs_basis.0.clone());
-	let mut result = LinearCombinations::one();
-
-	result = impl_mul_bases(
-		&result,
-		&rotor_basis
-			.0
-			.iter()
-			.map(|r| (Sign::Pos, r.clone()))
-			.collect::<Vec<_>>(),
-	);
-
-	result = impl_mul_bases(
-		&result,
-		&rhs_basis
-			.0
-			.iter()
-			.map(|r| (Sign::Pos, r.clone()))
-			.collect::<Vec<_>>(),
-	);
-
-	result = impl_mul_bases(
-		&result,
-		&rotor_basis
-			.0
-			.iter()
-			.map(|r| {
-				(
-					match r.grade() / 2 % 2 {
-						0 => Sign::Pos,
-						1 => Sign::Neg,
-						_ => unreachable!("maths broke"),
-					},
-					r.clone(),
-				)
-			})
-			.collect::<Vec<_>>(),
-	);
+
+	let result = sandwich(rotor_basis, rhs_basis);
 
 	let output_basis = rhs_basis;
 
-	let mut rows = vec![];
-	for term in &output_basis.0 {
-		let term_name = element_name(&term);
-		rows.push(quote! { #term_name : });
-		if let Some(sum) = result.0.get(&term) {
-			for (i, (sign, factors)) in sum.0.iter().enumerate() {
-				match sign {
-					Sign::Neg => rows.push(quote! {-}),
-					Sign::Pos if i != 0 => rows.push(quote! {+}),
-					_ => {}
-				}
-				let rot_factor = element_name(&factors[0]);
-				let rhs_name = element_name(&factors[1]);
-				let rotd_factor = element_name(&factors[2]);
-				rows.push(quote! { self.#rot_factor*rhs.#rhs_name*self.#rotd_factor});
-			}
-		} else {
-			rows.push(quote! { 0.0 });
-		}
-		rows.push(quote! {,});
-	}
+	let operands = [quote! { self }, quote! { rhs }, quote! { self }];
+	let names = basis_names(output_basis);
+	let exprs = output_basis
+		.0
+		.iter()
+		.map(|term| combination_expr(result.0.get(term), &operands));
 
 	gen.push(quote! {
 		impl gang::Rotate<#kind> for Rot {
 			type Output = #kind;
 			fn rotate(self, rhs: #kind) -> Self::Output {
 				Self::Output {
-					#(#rows)*
+					#(
+						#names: #exprs,
+					)*
 				}
 			}
 		}
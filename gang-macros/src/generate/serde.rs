@@ -0,0 +1,47 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::util::{basis_names, Basis};
+
+pub fn maybe_serde() -> TokenStream {
+	if cfg!(feature = "serde") {
+		quote! {
+			#[derive(gang::__private::serde::Serialize, gang::__private::serde::Deserialize)]
+			#[serde(crate = "gang::__private::serde")]
+		}
+	} else {
+		TokenStream::new()
+	}
+}
+
+/// Components in canonical order, for the compact sequence form
+pub fn impl_serde_seq(name: &Ident, basis: &Basis) -> TokenStream {
+	if !cfg!(feature = "serde") {
+		return TokenStream::new();
+	}
+
+	let els = basis_names(basis);
+	let len = els.len();
+	let indices = 0..len;
+	quote! {
+		impl gang::serde_seq::Components for #name {
+			type Array = [f32; #len];
+
+			fn to_components(&self) -> Self::Array {
+				[
+					#(
+						self.#els,
+					)*
+				]
+			}
+
+			fn from_components(arr: Self::Array) -> Self {
+				Self {
+					#(
+						#els: arr[#indices],
+					)*
+				}
+			}
+		}
+	}
+}
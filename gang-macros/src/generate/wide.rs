@@ -0,0 +1,283 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+
+use crate::{
+	util::{
+		basis_names, combination_expr_with, element_name, mul_bases, sandwich, wedge, Basis,
+		LinearCombination, LinearCombinations,
+	},
+	MvKind, Options,
+};
+
+/// Lane counts of the generated structure-of-arrays types
+pub const WIDTHS: [usize; 2] = [4, 8];
+
+pub fn wide_name(kind: MvKind, width: usize) -> Ident {
+	format_ident!("{}x{}", kind.into_token_stream().to_string(), width)
+}
+
+pub(crate) fn wide_types(
+	gen: &mut Vec<TokenStream>,
+	canonical_basis: &Basis,
+	kvectors: &[Basis],
+	rotor_basis: &Basis,
+	options: &Options,
+) {
+	for width in WIDTHS {
+		for &k in &options.grades {
+			let basis = &kvectors[k];
+			let kind = MvKind::KVector(k);
+			wide_struct(gen, kind, basis, width, options);
+			wide_linear_ops(gen, kind, basis, width);
+		}
+		wide_struct(gen, MvKind::Rotor, rotor_basis, width, options);
+		wide_linear_ops(gen, MvKind::Rotor, rotor_basis, width);
+		wide_struct(gen, MvKind::General, canonical_basis, width, options);
+		wide_linear_ops(gen, MvKind::General, canonical_basis, width);
+
+		for &k in &options.rotate {
+			let kind = MvKind::KVector(k);
+			// the factors of the sandwich are from `R`, `x` and `~R`
+			gen.push(soa_binary_op(
+				quote!(gang::Rotate),
+				quote!(rotate),
+				(MvKind::Rotor, kind, kind),
+				&sandwich(rotor_basis, &kvectors[k]),
+				&kvectors[k],
+				&[quote!(self), quote!(rhs), quote!(self)],
+				width,
+			));
+		}
+
+		for &(i, j) in &options.wedge {
+			gen.push(soa_binary_op(
+				quote!(gang::Wedge),
+				quote!(wedge),
+				(
+					MvKind::KVector(i),
+					MvKind::KVector(j),
+					MvKind::KVector(i + j),
+				),
+				&wedge(i, &kvectors[i], j, &kvectors[j]),
+				&kvectors[i + j],
+				&[quote!(self), quote!(rhs)],
+				width,
+			));
+		}
+
+		if options.has_rot_mul() {
+			gen.push(soa_binary_op(
+				quote!(core::ops::Mul),
+				quote!(mul),
+				(MvKind::Rotor, MvKind::Rotor, MvKind::Rotor),
+				&mul_bases(&[rotor_basis, rotor_basis]),
+				rotor_basis,
+				&[quote!(self), quote!(rhs)],
+				width,
+			));
+		}
+	}
+}
+
+fn wide_struct(
+	gen: &mut Vec<TokenStream>,
+	kind: MvKind,
+	basis: &Basis,
+	width: usize,
+	options: &Options,
+) {
+	let name = wide_name(kind, width);
+	let els = basis_names(basis);
+	let maybe_serde = super::maybe_serde();
+	let maybe_bevy_reflect = super::maybe_bevy_reflect(true);
+	gen.push(super::impl_bytemuck(&name, els.len() * width));
+	gen.push(super::impl_rand_wide(&name, kind, options));
+	gen.push(quote! {
+		/// Structure-of-arrays variant, each field holds one lane per element
+		#[derive(Clone, Copy, PartialEq, Debug)]
+		#maybe_bevy_reflect
+		#maybe_serde
+		#[repr(C)]
+		pub struct #name {
+			#(
+				pub #els: [f32; #width],
+			)*
+		}
+
+		impl #name {
+			pub fn splat(v: #kind) -> Self {
+				Self {
+					#(
+						#els: [v.#els; #width],
+					)*
+				}
+			}
+
+			pub fn from_lanes(lanes: [#kind; #width]) -> Self {
+				Self {
+					#(
+						#els: core::array::from_fn(|i| lanes[i].#els),
+					)*
+				}
+			}
+
+			pub fn to_lanes(self) -> [#kind; #width] {
+				core::array::from_fn(|i| self.lane(i))
+			}
+
+			pub fn lane(&self, i: usize) -> #kind {
+				#kind {
+					#(
+						#els: self.#els[i],
+					)*
+				}
+			}
+
+			pub fn set_lane(&mut self, i: usize, v: #kind) {
+				#(
+					self.#els[i] = v.#els;
+				)*
+			}
+
+			pub fn norm(self) -> [f32; #width] {
+				core::array::from_fn(|i| {
+					gang::math::sqrt(
+						#(
+							self.#els[i]*self.#els[i]
+						)+*
+					)
+				})
+			}
+
+			pub fn normalize(self) -> Self {
+				let norm = self.norm();
+				Self {
+					#(
+						#els: core::array::from_fn(|i| self.#els[i] * norm[i].recip()),
+					)*
+				}
+			}
+		}
+
+		impl Default for #name {
+			fn default() -> Self {
+				Self::splat(#kind::default())
+			}
+		}
+
+		impl From<[#kind; #width]> for #name {
+			fn from(lanes: [#kind; #width]) -> Self {
+				Self::from_lanes(lanes)
+			}
+		}
+
+		impl From<#name> for [#kind; #width] {
+			fn from(v: #name) -> Self {
+				v.to_lanes()
+			}
+		}
+
+		impl core::ops::Mul<f32> for #name {
+			type Output = Self;
+			fn mul(self, rhs: f32) -> Self::Output {
+				Self {
+					#(
+						#els: self.#els.map(|x| x * rhs),
+					)*
+				}
+			}
+		}
+	});
+}
+
+fn wide_linear_ops(gen: &mut Vec<TokenStream>, kind: MvKind, basis: &Basis, width: usize) {
+	let name = wide_name(kind, width);
+	let els = basis_names(basis);
+	gen.push(quote! {
+		impl core::ops::Add<#name> for #name {
+			type Output = #name;
+			fn add(self, rhs: #name) -> Self::Output {
+				Self::Output {
+					#(
+						#els: core::array::from_fn(|i| self.#els[i] + rhs.#els[i]),
+					)*
+				}
+			}
+		}
+
+		impl core::ops::AddAssign<#name> for #name {
+			fn add_assign(&mut self, rhs: #name) {
+				*self = *self + rhs;
+			}
+		}
+
+		impl core::ops::Sub<#name> for #name {
+			type Output = #name;
+			fn sub(self, rhs: #name) -> Self::Output {
+				Self::Output {
+					#(
+						#els: core::array::from_fn(|i| self.#els[i] - rhs.#els[i]),
+					)*
+				}
+			}
+		}
+
+		impl core::ops::SubAssign<#name> for #name {
+			fn sub_assign(&mut self, rhs: #name) {
+				*self = *self - rhs;
+			}
+		}
+
+		impl core::ops::Neg for #name {
+			type Output = #name;
+			fn neg(self) -> Self::Output {
+				Self::Output {
+					#(
+						#els: self.#els.map(|x| -x),
+					)*
+				}
+			}
+		}
+	});
+}
+
+/// Sum of the terms of a product on lane `i`, the `n`-th factor of each term taken from `operands[n]`
+fn lane_expr(combination: Option<&LinearCombination>, operands: &[TokenStream]) -> TokenStream {
+	combination_expr_with(combination, |n, factor| {
+		let operand = &operands[n];
+		let name = element_name(factor);
+		quote! { #operand.#name[i] }
+	})
+}
+
+/// Binary operation computed on whole component arrays, from the terms of the scalar product
+fn soa_binary_op(
+	trait_name: TokenStream,
+	method: TokenStream,
+	(lhs, rhs, output): (MvKind, MvKind, MvKind),
+	combinations: &LinearCombinations,
+	output_basis: &Basis,
+	operands: &[TokenStream],
+	width: usize,
+) -> TokenStream {
+	let lhs_name = wide_name(lhs, width);
+	let rhs_name = wide_name(rhs, width);
+	let output_name = wide_name(output, width);
+	let els = basis_names(output_basis);
+	let exprs = output_basis
+		.0
+		.iter()
+		.map(|el| lane_expr(combinations.0.get(el), operands));
+	quote! {
+		impl #trait_name<#rhs_name> for #lhs_name {
+			type Output = #output_name;
+			fn #method(self, rhs: #rhs_name) -> Self::Output {
+				Self::Output {
+					#(
+						#els: core::array::from_fn(|i| #exprs),
+					)*
+				}
+			}
+		}
+	}
+}
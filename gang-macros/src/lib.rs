@@ -1,50 +1,18 @@
+use options::{MacroInput, Options};
 use proc_macro::TokenStream;
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use syn::Ident;
-use util::{element_name, infer, Basis, Element, Sign};
+use util::{element_name, infer, Basis, Element};
 
 #[proc_macro]
 pub fn gang(input: TokenStream) -> TokenStream {
-	let ast: syn::LitInt = syn::parse(input).unwrap();
-
-	let dim = ast.base10_parse::<u32>().unwrap();
-
-	// basis elements - products of basis vectors in increasing order
-	let canonical_basis = {
-		let mut basis: Vec<Element> = Vec::new();
-		for i in 0..2_u32.pow(dim) {
-			let mut elem = Vec::new();
-			for j in 0..2_u32.pow(dim) {
-				if (i >> j) % 2 == 1 {
-					elem.push(j)
-				}
-			}
-			basis.push(Element(elem));
-		}
-		basis.sort_by(|a, b| a.0.len().cmp(&b.0.len()).then_with(|| a.0.cmp(&b.0)));
-		Basis(basis)
-	};
+	let MacroInput { dim, options } = syn::parse_macro_input!(input as MacroInput);
 
-	// k-vectors - grade k multivectors
-	let mut kvectors: Vec<Basis> = Vec::new();
-	for g in 0..=dim {
-		kvectors.push(Basis(
-			canonical_basis
-				.0
-				.iter()
-				.cloned()
-				.filter(|c| c.grade() as u32 == g)
-				.collect::<Vec<_>>(),
-		))
-	}
-
-	// rotor - sum of 2k-vectors
-	let mut rotor_basis = Basis(Vec::new());
-	for c in &canonical_basis.0 {
-		if c.grade() % 2 == 0 {
-			rotor_basis.0.push(c.clone());
-		}
-	}
+	let Algebra {
+		canonical_basis,
+		kvectors,
+		rotor_basis,
+	} = Algebra::new(dim);
 
 	// code output
 	let mut gen: Vec<proc_macro2::TokenStream> = vec![];
@@ -63,17 +31,15 @@ pub fn gang(input: TokenStream) -> TokenStream {
 				proc_macro2::TokenStream::new()
 			};
 
-			let maybe_bevy_reflect = if cfg!(feature = "bevy_reflect") {
-				quote! {
-					#[derive(bevy_reflect::Reflect)]
-				}
-			} else {
-				proc_macro2::TokenStream::new()
-			};
+			let maybe_bevy_reflect = generate::maybe_bevy_reflect(true);
+
+			let maybe_serde = generate::maybe_serde();
 
 			gen.push(quote! {
 				#[derive(Clone, Copy, PartialEq, Debug)]
 				#maybe_bevy_reflect
+				#maybe_serde
+				#[repr(C)]
 				pub struct #name {
 					#(
 						pub #bases: f32,
@@ -83,6 +49,7 @@ pub fn gang(input: TokenStream) -> TokenStream {
 				impl #name {
 					#constants_tokens
 
+					#[allow(clippy::too_many_arguments)]
 					pub fn new(#(#bases: f32,)*) -> Self {
 						Self {
 							#(
@@ -92,16 +59,24 @@ pub fn gang(input: TokenStream) -> TokenStream {
 					}
 				}
 			});
+
+			gen.push(generate::impl_serde_seq(name, basis));
+			gen.push(generate::impl_bytemuck(name, basis.0.len()));
+			gen.push(generate::impl_approx(name, basis));
+			if options.layouts {
+				gen.push(generate::gpu_layouts(name, basis));
+			}
 		};
 
 	// generate k-vectors
-	for (k, basis) in kvectors.iter().enumerate() {
+	for &k in &options.grades {
+		let basis = &kvectors[k];
 		let name = format_ident!("V{k}");
-		gen_specialized_mv_struct(&mut gen, &name, &basis, true);
+		gen_specialized_mv_struct(&mut gen, &name, basis, true);
 		let bases = basis
 			.0
 			.iter()
-			.map(|c| format_ident!("{}", element_name(&c)))
+			.map(|c| format_ident!("{}", element_name(c)))
 			.collect::<Vec<_>>();
 		gen.push(quote! {
 			impl #name {
@@ -130,8 +105,8 @@ pub fn gang(input: TokenStream) -> TokenStream {
 		let other_element_names = rotor_basis
 			.0
 			.iter()
-			.filter(|c| !c.0.is_empty()) // e is written explicitly below
-			.map(|c| format_ident!("{}", element_name(&c)))
+			.filter(|c| c.0 != 0) // e is written explicitly below
+			.map(|c| format_ident!("{}", element_name(c)))
 			.collect::<Vec<_>>();
 		gen_specialized_mv_struct(&mut gen, &format_ident!("Rot"), &rotor_basis, false);
 		gen.push(quote! {
@@ -161,32 +136,35 @@ pub fn gang(input: TokenStream) -> TokenStream {
 			.iter()
 			.filter_map(|e| (e.grade() != 0 && e.grade() != 2).then_some(element_name(e)))
 			.collect();
-		gen.push(quote! {
-			impl Rot {
-				pub fn from_v2_angle(v2: V2, angle: f32) -> Self {
-					let a = angle / 2.0;
-					let (s, c) = a.sin_cos();
-					Self {
-						e: c,
-						#(
-							#v2_elements: -v2.#v2_elements*s,
-						)*
-						#(
-							#missing_elements: 0.0,
-						)*
+		// there is no plane to rotate in below 2 dimensions
+		if dim >= 2 {
+			gen.push(quote! {
+				impl Rot {
+					pub fn from_v2_angle(v2: V2, angle: f32) -> Self {
+						let a = angle / 2.0;
+						let (s, c) = gang::math::sin_cos(a);
+						Self {
+							e: c,
+							#(
+								#v2_elements: -v2.#v2_elements*s,
+							)*
+							#(
+								#missing_elements: 0.0,
+							)*
+						}
 					}
 				}
-			}
-		});
+			});
+		}
 
-		if kvectors[2].0.len() == 1 {
+		if dim == 2 {
 			let single_element = element_name(&kvectors[2].0[0]);
 			// angle() can probably work in 3d too, but I don't think it can work in 4D
 			gen.push(quote! {
 				impl Rot {
 					pub fn from_angle(angle: f32) -> Self {
 						let a = angle / 2.0;
-						let (s, c) = a.sin_cos();
+						let (s, c) = gang::math::sin_cos(a);
 						Self {
 							e: c,
 							#single_element: -s
@@ -194,29 +172,94 @@ pub fn gang(input: TokenStream) -> TokenStream {
 					}
 
 					pub fn angle(self) -> f32 {
-						2.0*(-self.#single_element).atan2(self.e)
+						2.0*gang::math::atan2(-self.#single_element, self.e)
 					}
 				}
 			});
 		}
 	}
 
+	// general multivector - all the elements, for the results of mixed grades
+	{
+		let element_names = canonical_basis
+			.0
+			.iter()
+			.map(|c| format_ident!("{}", element_name(c)))
+			.collect::<Vec<_>>();
+		gen_specialized_mv_struct(&mut gen, &format_ident!("Mv"), &canonical_basis, true);
+		gen.push(quote! {
+			impl Mv {
+				pub const ZERO: Self = Self {
+					#(
+						#element_names: 0.0,
+					)*
+				};
+			}
+
+			impl Default for Mv {
+				fn default() -> Self {
+					Self::ZERO
+				}
+			}
+		});
+	}
+
 	generate::rotor_methods(&mut gen, &rotor_basis);
+	generate::impl_rotor_approx(&mut gen);
+
+	for &(lhs, rhs) in &options.geometric {
+		impl_mul(&mut gen, &canonical_basis.0, lhs, rhs, &options.grades);
+	}
+
+	generate::kvector_methods(&mut gen, &canonical_basis, &kvectors, &options);
+	generate::mv_methods(&mut gen, &canonical_basis, &rotor_basis);
 
-	impl_mul(
-		&mut gen,
-		&canonical_basis.0,
-		(format_ident!("Rot"), &rotor_basis),
-		(format_ident!("Rot"), &rotor_basis),
-	);
+	for &k in &options.rotate {
+		generate::impl_rotate(&mut gen, &rotor_basis, &kvectors[k]);
+		generate::impl_to_matrix(&mut gen, &rotor_basis, &kvectors[k]);
+		generate::impl_rotate_batch(&mut gen, &kvectors[k]);
+	}
+	if options.has_from_matrix() {
+		generate::impl_from_matrix(&mut gen, &kvectors[1]);
+	}
 
-	generate::kvector_methods(&mut gen, &canonical_basis, &kvectors);
+	generate::impl_algebra(&mut gen, dim, &options);
 
-	for elements in &kvectors {
-		generate::impl_rotate(&mut gen, &rotor_basis, elements);
+	if options.layouts {
+		generate::wide_types(
+			&mut gen,
+			&canonical_basis,
+			&kvectors,
+			&rotor_basis,
+			&options,
+		);
 	}
 
-	generate::impl_to_matrix(&mut gen, &rotor_basis, &kvectors[1]);
+	generate::impl_mint(&mut gen, &kvectors[1], &options);
+	generate::impl_glam(&mut gen, &kvectors[1], &options);
+	generate::impl_nalgebra(&mut gen, &kvectors, &options);
+	generate::impl_rand(&mut gen, &kvectors, &options);
+	generate::impl_proptest(&mut gen, &kvectors, &options);
+
+	{
+		let kinds = options
+			.grades
+			.iter()
+			.copied()
+			.map(MvKind::KVector)
+			.chain([MvKind::Rotor, MvKind::General])
+			.collect::<Vec<_>>();
+		let mut names = vec![];
+		for &kind in &kinds {
+			let name = format_ident!("{}", kind.into_token_stream().to_string());
+			if options.layouts {
+				names.push(generate::std140_name(&name));
+				names.extend(generate::WIDTHS.map(|width| generate::wide_name(kind, width)));
+			}
+			names.push(name);
+		}
+		generate::impl_bevy_reflect(&mut gen, &names);
+	}
 
 	quote! {
 		#(#gen)*
@@ -224,64 +267,127 @@ pub fn gang(input: TokenStream) -> TokenStream {
 	.into()
 }
 
+/// WGSL source of the structs and products of `gang!`, as a string literal
+#[proc_macro]
+pub fn wgsl(input: TokenStream) -> TokenStream {
+	shader(input, shader::Language::Wgsl)
+}
+
+/// GLSL source of the structs and products of `gang!`, as a string literal
+#[proc_macro]
+pub fn glsl(input: TokenStream) -> TokenStream {
+	shader(input, shader::Language::Glsl)
+}
+
+fn shader(input: TokenStream, lang: shader::Language) -> TokenStream {
+	let MacroInput { dim, options } = syn::parse_macro_input!(input as MacroInput);
+
+	let source = shader::generate(dim, &options, lang);
+	quote! { #source }.into()
+}
+
 mod generate;
+mod options;
+mod shader;
 mod util;
 
+/// Bases of the algebra of a given dimension
+struct Algebra {
+	canonical_basis: Basis,
+	kvectors: Vec<Basis>,
+	rotor_basis: Basis,
+}
+
+impl Algebra {
+	fn new(dim: u32) -> Self {
+		// basis elements - products of basis vectors in increasing order
+		let canonical_basis = {
+			let mut basis: Vec<Element> = (0..2_u32.pow(dim)).map(Element).collect();
+			basis.sort_by(|a, b| a.grade().cmp(&b.grade()).then_with(|| a.cmp(b)));
+			Basis(basis)
+		};
+
+		// k-vectors - grade k multivectors
+		let mut kvectors: Vec<Basis> = Vec::new();
+		for g in 0..=dim {
+			kvectors.push(Basis(
+				canonical_basis
+					.0
+					.iter()
+					.filter(|&c| c.grade() as u32 == g)
+					.copied()
+					.collect::<Vec<_>>(),
+			))
+		}
+
+		// rotor - sum of 2k-vectors
+		let mut rotor_basis = Basis(Vec::new());
+		for c in &canonical_basis.0 {
+			if c.grade() % 2 == 0 {
+				rotor_basis.0.push(*c);
+			}
+		}
+
+		Self {
+			canonical_basis,
+			kvectors,
+			rotor_basis,
+		}
+	}
+}
+
+/// The geometric product `lhs * rhs`, into the smallest type holding the result.
+///
+/// It is skipped when the result is a k-vector whose grade isn't generated.
 fn impl_mul(
 	gen: &mut Vec<proc_macro2::TokenStream>,
 	elements: &[Element],
-	lhs: (Ident, &Basis),
-	rhs: (Ident, &Basis),
+	lhs_kind: MvKind,
+	rhs_kind: MvKind,
+	grades: &[usize],
 ) {
-	let lhs_name = lhs.0;
-	let rhs_name = rhs.0;
-
 	let (calc_map, output_kind) = {
-		let map = util::mul_bases(&[&lhs.1, &rhs.1]);
-		(
-			map.0.clone(),
-			infer(map.0.keys().cloned().collect::<Vec<_>>()),
-		)
+		let lhs_basis = Basis(lhs_kind.get_elements(elements));
+		let rhs_basis = Basis(rhs_kind.get_elements(elements));
+		let map = util::mul_bases(&[&lhs_basis, &rhs_basis]);
+		let kind = match infer(map.0.keys().cloned().collect::<Vec<_>>()) {
+			// rotors are closed under the product, even in 1D where they are only scalars
+			MvKind::KVector(0) if lhs_kind == MvKind::Rotor && rhs_kind == MvKind::Rotor => {
+				MvKind::Rotor
+			}
+			kind => kind,
+		};
+		(map.0, kind)
 	};
+	if let MvKind::KVector(k) = output_kind {
+		if !grades.contains(&k) {
+			return;
+		}
+	}
 
 	let output_basis = output_kind.get_elements(elements);
 
-	let mut rows = vec![];
-	for term in output_basis {
-		let term_name = element_name(&term);
-		rows.push(quote! { #term_name : });
-		if let Some(sum) = calc_map.get(&term) {
-			for (i, (sign, terms)) in sum.0.iter().enumerate() {
-				match sign {
-					Sign::Neg => rows.push(quote! {-}),
-					Sign::Pos if i != 0 => rows.push(quote! {+}),
-					_ => {}
-				}
-				let lhs_name = element_name(&terms[0]);
-				let rhs_name = element_name(&terms[1]);
-				rows.push(quote! { self.#lhs_name * rhs.#rhs_name});
-			}
-		} else {
-			rows.push(quote! { 0.0 });
-		}
-		rows.push(quote! {,});
-	}
+	let operands = [quote! { self }, quote! { rhs }];
+	let names = output_basis.iter().map(element_name);
+	let exprs = output_basis
+		.iter()
+		.map(|term| util::combination_expr(calc_map.get(term), &operands));
 
 	gen.push(quote! {
-		impl std::ops::Mul<#rhs_name> for #lhs_name {
+		impl core::ops::Mul<#rhs_kind> for #lhs_kind {
 			type Output = #output_kind;
-			fn mul(self, rhs: #rhs_name) -> Self::Output {
+			fn mul(self, rhs: #rhs_kind) -> Self::Output {
 				Self::Output {
-					#(#rows)*
+					#(
+						#names: #exprs,
+					)*
 				}
 			}
 		}
 	});
 }
 
-// type Element = Vec<u32>;
-
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum MvKind {
 	KVector(usize),
 	Rotor,
@@ -293,13 +399,13 @@ impl MvKind {
 		match self {
 			MvKind::KVector(k) => elements
 				.iter()
-				.cloned()
-				.filter(|e| e.0.len() == *k)
+				.filter(|&e| e.grade() == *k)
+				.copied()
 				.collect(),
 			MvKind::Rotor => elements
 				.iter()
-				.cloned()
-				.filter(|e| e.0.len() % 2 == 0)
+				.filter(|&e| e.grade() % 2 == 0)
+				.copied()
 				.collect(),
 			MvKind::General => elements.to_owned(),
 		}
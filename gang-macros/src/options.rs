@@ -0,0 +1,215 @@
+//! Input of the macros: the dimension, then optional lists of what to generate.
+//!
+//! `gang!(5, products = [rotate, geometric], grades = [1, 2])` only generates
+//! `V1`, `V2`, `Rot` and `Mv`, with `Rotate` and `*` but no `Wedge`.
+//!
+//! The `gN` modules of `gang` use the defaults, since the options aren't exposed
+//! through its features.
+
+use syn::{
+	bracketed,
+	parse::{Parse, ParseStream},
+	punctuated::Punctuated,
+	Ident, LitInt, Token,
+};
+
+use crate::MvKind;
+
+pub(crate) struct MacroInput {
+	pub dim: u32,
+	pub options: Options,
+}
+
+impl Parse for MacroInput {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let dim_lit: LitInt = input.parse()?;
+		let dim = dim_lit.base10_parse::<u32>()?;
+		if !(1..=8).contains(&dim) {
+			return Err(syn::Error::new(
+				dim_lit.span(),
+				"the dimension must be between 1 and 8",
+			));
+		}
+
+		let mut grades = None;
+		let mut products = None;
+		while !input.is_empty() {
+			input.parse::<Token![,]>()?;
+			if input.is_empty() {
+				break;
+			}
+			let key: Ident = input.parse()?;
+			input.parse::<Token![=]>()?;
+			let content;
+			bracketed!(content in input);
+			match key.to_string().as_str() {
+				"grades" => {
+					let mut list = vec![];
+					for lit in Punctuated::<LitInt, Token![,]>::parse_terminated(&content)? {
+						let grade = lit.base10_parse::<usize>()?;
+						if grade > dim as usize {
+							return Err(syn::Error::new(
+								lit.span(),
+								format!("there is no grade {grade} in dimension {dim}"),
+							));
+						}
+						list.push(grade);
+					}
+					grades = Some(list);
+				}
+				"products" => {
+					let mut list = vec![];
+					for product in Punctuated::<Ident, Token![,]>::parse_terminated(&content)? {
+						let product =
+							match product.to_string().as_str() {
+								"geometric" => Product::Geometric,
+								"rotate" => Product::Rotate,
+								"wedge" => Product::Wedge,
+								_ => return Err(syn::Error::new(
+									product.span(),
+									"unknown product, expected `geometric`, `rotate` or `wedge`",
+								)),
+							};
+						list.push(product);
+					}
+					products = Some(list);
+				}
+				_ => {
+					return Err(syn::Error::new(
+						key.span(),
+						"unknown option, expected `products` or `grades`",
+					))
+				}
+			}
+		}
+
+		Ok(Self {
+			dim,
+			options: Options::new(dim as usize, grades, products),
+		})
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Product {
+	/// `*` between every pair of types
+	Geometric,
+	/// `Rotate`, rotation matrices and `RotateBatch`
+	Rotate,
+	/// `Wedge`
+	Wedge,
+}
+
+/// What is generated on top of `Rot`.
+///
+/// Without a `products` list, `*`, `Rotate` and `Wedge` are generated for every
+/// type up to 5 dimensions. From 6 dimensions, only the core products are, unless
+/// the `gN_geometric`, `gN_rotate` and `gN_wedge` features add the others.
+pub(crate) struct Options {
+	/// Grades of the generated k-vectors, always including the vectors and
+	/// bivectors that rotors are built from
+	pub grades: Vec<usize>,
+	/// Pairs of types with the geometric product `*`
+	pub geometric: Vec<(MvKind, MvKind)>,
+	/// Grades with `Rotate`, a rotation matrix and `RotateBatch`
+	pub rotate: Vec<usize>,
+	/// Grades pairs with `Wedge`
+	pub wedge: Vec<(usize, usize)>,
+	/// The structure-of-arrays types and the std140/std430 mirrors, only up to 5
+	/// dimensions, where they stay cheap to compile
+	pub layouts: bool,
+}
+
+impl Options {
+	pub fn new(dim: usize, grades: Option<Vec<usize>>, products: Option<Vec<Product>>) -> Self {
+		let mut grades = match grades {
+			Some(grades) => grades,
+			None => (0..=dim).collect(),
+		};
+		grades.extend([1, 2].into_iter().filter(|&k| k <= dim));
+		grades.sort();
+		grades.dedup();
+
+		// without a list, the core products are `Rot * Rot`, `V1 * Rot`, `Rot * V1` and `Mv * Mv`
+		// (only `Rot * Rot` in 8 dimensions, where `Mv * Mv` alone takes close to two minutes to compile),
+		// rotating vectors and the wedge of two vectors
+		let core = products.is_none();
+		let (all_geometric, all_rotate, all_wedge) = match products {
+			Some(products) => (
+				products.contains(&Product::Geometric),
+				products.contains(&Product::Rotate),
+				products.contains(&Product::Wedge),
+			),
+			None => match dim {
+				..=5 => (true, true, true),
+				6 => (
+					cfg!(feature = "g6_geometric"),
+					cfg!(feature = "g6_rotate"),
+					cfg!(feature = "g6_wedge"),
+				),
+				7 => (
+					cfg!(feature = "g7_geometric"),
+					cfg!(feature = "g7_rotate"),
+					cfg!(feature = "g7_wedge"),
+				),
+				_ => (false, false, false),
+			},
+		};
+
+		let geometric = match (all_geometric, core) {
+			(true, _) => {
+				let kinds = grades
+					.iter()
+					.map(|&k| MvKind::KVector(k))
+					.chain([MvKind::Rotor, MvKind::General])
+					.collect::<Vec<_>>();
+				kinds
+					.iter()
+					.flat_map(|&lhs| kinds.iter().map(move |&rhs| (lhs, rhs)))
+					.collect()
+			}
+			(false, true) if dim >= 8 => vec![(MvKind::Rotor, MvKind::Rotor)],
+			(false, true) => vec![
+				(MvKind::Rotor, MvKind::Rotor),
+				(MvKind::KVector(1), MvKind::Rotor),
+				(MvKind::Rotor, MvKind::KVector(1)),
+				(MvKind::General, MvKind::General),
+			],
+			(false, false) => vec![],
+		};
+		let rotate = match (all_rotate, core) {
+			(true, _) => grades.clone(),
+			(false, true) => vec![1],
+			(false, false) => vec![],
+		};
+		let wedge = match (all_wedge, core) {
+			(true, _) => grades
+				.iter()
+				.flat_map(|&i| grades.iter().map(move |&j| (i, j)))
+				.collect(),
+			(false, true) => vec![(1, 1)],
+			(false, false) => vec![],
+		};
+
+		Self {
+			wedge: wedge
+				.into_iter()
+				.filter(|(i, j)| i + j <= dim && grades.contains(&(i + j)))
+				.collect(),
+			grades,
+			geometric,
+			rotate,
+			layouts: dim <= 5,
+		}
+	}
+
+	/// `Rot * Rot`, which the constructions of unit rotors rely on
+	pub fn has_rot_mul(&self) -> bool {
+		self.geometric.contains(&(MvKind::Rotor, MvKind::Rotor))
+	}
+
+	/// `Rot::from_v1_rotation_matrix` composes plane rotations with the geometric product
+	pub fn has_from_matrix(&self) -> bool {
+		self.has_rot_mul() && self.rotate.contains(&1)
+	}
+}
@@ -0,0 +1,135 @@
+//! Shader source for the same structs and products as the Rust code.
+//!
+//! The products come from the same linear combinations as `generate`,
+//! so the results match the CPU side term for term.
+
+use std::fmt::Write;
+
+use quote::ToTokens;
+
+use crate::{
+	util::{combination_source, element_name, mul_bases, sandwich, wedge, Basis},
+	Algebra, MvKind, Options,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Language {
+	Wgsl,
+	Glsl,
+}
+
+impl Language {
+	fn write_struct(self, out: &mut String, name: &str, basis: &Basis) {
+		match self {
+			Language::Wgsl => {
+				writeln!(out, "struct {name} {{").unwrap();
+				for el in &basis.0 {
+					writeln!(out, "\t{}: f32,", element_name(el)).unwrap();
+				}
+				writeln!(out, "}}\n").unwrap();
+			}
+			Language::Glsl => {
+				writeln!(out, "struct {name} {{").unwrap();
+				for el in &basis.0 {
+					writeln!(out, "\tfloat {};", element_name(el)).unwrap();
+				}
+				writeln!(out, "}};\n").unwrap();
+			}
+		}
+	}
+
+	fn write_fn_header(self, out: &mut String, name: &str, lhs: &str, rhs: &str, output: &str) {
+		match self {
+			Language::Wgsl => {
+				writeln!(out, "fn {name}(lhs: {lhs}, rhs: {rhs}) -> {output} {{").unwrap()
+			}
+			Language::Glsl => writeln!(out, "{output} {name}({lhs} lhs, {rhs} rhs) {{").unwrap(),
+		}
+	}
+}
+
+fn kind_name(kind: MvKind) -> String {
+	kind.into_token_stream().to_string()
+}
+
+fn write_fn(
+	out: &mut String,
+	lang: Language,
+	name: &str,
+	(lhs, rhs, output): (MvKind, MvKind, MvKind),
+	rows: Vec<String>,
+) {
+	let output_name = kind_name(output);
+	lang.write_fn_header(out, name, &kind_name(lhs), &kind_name(rhs), &output_name);
+	writeln!(out, "\treturn {output_name}(").unwrap();
+	writeln!(out, "\t\t{}", rows.join(",\n\t\t")).unwrap();
+	writeln!(out, "\t);\n}}\n").unwrap();
+}
+
+pub fn generate(dim: u32, options: &Options, lang: Language) -> String {
+	let Algebra {
+		kvectors,
+		rotor_basis,
+		..
+	} = Algebra::new(dim);
+
+	let mut out = String::new();
+
+	for &k in &options.grades {
+		lang.write_struct(&mut out, &kind_name(MvKind::KVector(k)), &kvectors[k]);
+	}
+	lang.write_struct(&mut out, "Rot", &rotor_basis);
+
+	if options.has_rot_mul() {
+		let product = mul_bases(&[&rotor_basis, &rotor_basis]);
+		write_fn(
+			&mut out,
+			lang,
+			"rot_mul_rot",
+			(MvKind::Rotor, MvKind::Rotor, MvKind::Rotor),
+			rotor_basis
+				.0
+				.iter()
+				.map(|el| combination_source(product.0.get(el), &["lhs", "rhs"]))
+				.collect(),
+		);
+	}
+
+	for &k in &options.rotate {
+		let kind = MvKind::KVector(k);
+		let basis = &kvectors[k];
+		let result = sandwich(&rotor_basis, basis);
+		write_fn(
+			&mut out,
+			lang,
+			&format!("rot_rotate_v{k}"),
+			(MvKind::Rotor, kind, kind),
+			basis
+				.0
+				.iter()
+				.map(|el| combination_source(result.0.get(el), &["lhs", "rhs", "lhs"]))
+				.collect(),
+		);
+	}
+
+	for &(i, j) in &options.wedge {
+		let result = wedge(i, &kvectors[i], j, &kvectors[j]);
+		write_fn(
+			&mut out,
+			lang,
+			&format!("v{i}_wedge_v{j}"),
+			(
+				MvKind::KVector(i),
+				MvKind::KVector(j),
+				MvKind::KVector(i + j),
+			),
+			kvectors[i + j]
+				.0
+				.iter()
+				.map(|el| combination_source(result.0.get(el), &["lhs", "rhs"]))
+				.collect(),
+		);
+	}
+
+	out
+}
@@ -1,35 +1,20 @@
 use std::{
+	cmp::Ordering,
 	collections::{BTreeMap, HashSet},
 	fmt::Display,
 };
 
-use proc_macro2::Ident;
-use quote::format_ident;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
 
 use crate::MvKind;
 
-pub(crate) fn gnome_sort(input: &[u32]) -> (i32, Vec<u32>) {
-	let mut v = input.to_vec();
-	let mut i = 0;
-	let mut sign = 1;
-	while i < v.len() {
-		if i == 0 || v[i - 1] <= v[i] {
-			i += 1;
-		} else {
-			v.swap(i - 1, i);
-			sign = -sign;
-			i -= 1;
-		}
-	}
-	for i in (1..v.len()).rev() {
-		if i >= v.len() {
-			continue;
-		}
-		if v[i - 1] == v[i] {
-			v.drain(i - 1..i + 1);
-		}
+/// Sign of reordering the vectors of `lhs` followed by those of `rhs` into increasing order
+pub(crate) fn reordering_sign(lhs: u32, rhs: u32) -> Sign {
+	match gang_blade::reordering_sign(lhs, rhs) {
+		1 => Sign::Pos,
+		_ => Sign::Neg,
 	}
-	(sign, v)
 }
 
 #[derive(Debug, Clone)]
@@ -45,14 +30,14 @@ impl Display for LinearCombination {
 					Sign::Pos => "+",
 					Sign::Neg => "-",
 				};
-				let els_str = (els.len() > 0)
-					.then_some(
-						els.iter()
-							.map(|el| format!("{}", el))
-							.collect::<Vec<_>>()
-							.join(" "),
-					)
-					.unwrap_or(String::from("1"));
+				let els_str = if els.is_empty() {
+					String::from("1")
+				} else {
+					els.iter()
+						.map(|el| format!("{}", el))
+						.collect::<Vec<_>>()
+						.join(" ")
+				};
 				format!("{} {}", sign_str, els_str)
 			})
 			.collect::<Vec<_>>()
@@ -71,14 +56,89 @@ impl LinearCombination {
 	}
 }
 
+/// Terms of a sum, with the sign to write before each: none for a leading positive term
+fn signed_terms(sum: &LinearCombination) -> impl Iterator<Item = (Option<Sign>, &[Element])> {
+	sum.0.iter().enumerate().map(|(i, (sign, factors))| {
+		let sign = match sign {
+			Sign::Pos if i == 0 => None,
+			sign => Some(*sign),
+		};
+		(sign, factors.as_slice())
+	})
+}
+
+/// Sum of the terms of a product, the `n`-th factor of each term being the field of
+/// `operands[n]`, or `0.0` without terms
+pub(crate) fn combination_expr(
+	combination: Option<&LinearCombination>,
+	operands: &[TokenStream],
+) -> TokenStream {
+	combination_expr_with(combination, |n, factor| {
+		let operand = &operands[n];
+		let name = element_name(factor);
+		quote! { #operand.#name }
+	})
+}
+
+/// Sum of the terms of a product, the `n`-th factor of each term being `factor(n, element)`
+pub(crate) fn combination_expr_with(
+	combination: Option<&LinearCombination>,
+	factor: impl Fn(usize, &Element) -> TokenStream,
+) -> TokenStream {
+	let Some(sum) = combination.filter(|sum| !sum.0.is_empty()) else {
+		return quote! { 0.0 };
+	};
+	let mut expr = TokenStream::new();
+	for (sign, factors) in signed_terms(sum) {
+		match sign {
+			Some(Sign::Pos) => expr.extend(quote! {+}),
+			Some(Sign::Neg) => expr.extend(quote! {-}),
+			None => {}
+		}
+		for (n, el) in factors.iter().enumerate() {
+			if n != 0 {
+				expr.extend(quote! {*});
+			}
+			expr.extend(factor(n, el));
+		}
+	}
+	expr
+}
+
+/// [`combination_expr`] as shader source, with the operands given by name
+pub(crate) fn combination_source(
+	combination: Option<&LinearCombination>,
+	operands: &[&str],
+) -> String {
+	let Some(sum) = combination.filter(|sum| !sum.0.is_empty()) else {
+		return String::from("0.0");
+	};
+	let mut out = String::new();
+	for (i, (sign, factors)) in signed_terms(sum).enumerate() {
+		match sign {
+			Some(Sign::Neg) if i == 0 => out.push('-'),
+			Some(sign) => out.push_str(&format!(" {sign} ")),
+			None => {}
+		}
+		let product = factors
+			.iter()
+			.zip(operands)
+			.map(|(factor, operand)| format!("{operand}.{}", element_name(factor)))
+			.collect::<Vec<_>>()
+			.join(" * ");
+		out.push_str(&product);
+	}
+	out
+}
+
 #[derive(Debug)]
 pub struct LinearCombinations(pub BTreeMap<Element, LinearCombination>);
 
 impl Display for LinearCombinations {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(f, "LinearCombination {{\n")?;
+		writeln!(f, "LinearCombination {{")?;
 		for (el, combination) in &self.0 {
-			write!(f, "\t{}: {},\n", el, combination)?;
+			writeln!(f, "\t{}: {},", el, combination)?;
 		}
 		write!(f, "}}")
 	}
@@ -90,39 +150,27 @@ impl LinearCombinations {
 	}
 
 	pub fn one() -> Self {
-		Self([(Element(vec![]), LinearCombination::one())].into())
+		Self([(Element(0), LinearCombination::one())].into())
 	}
 }
 
+/// Multiplies every term of `a` by every signed element of `b`, appending the element to the
+/// factors of the term
 pub fn impl_mul_bases(a: &LinearCombinations, b: &[(Sign, Element)]) -> LinearCombinations {
-	let mut out: LinearCombinations = LinearCombinations::zero();
-	for a_unit in a.0.keys() {
-		for (b_sign, b_unit) in b {
-			let (sign, resulting_unit) = (*a_unit).clone() * (*b_unit).clone();
-			let already = out.0.get(&resulting_unit);
-			match already {
-				Some(v) => {
-					let mut v = v.clone();
-					v.0.extend(a.0[&a_unit].clone().0.into_iter().map(|(s, term)| {
-						(s * sign * *b_sign, [term, vec![b_unit.clone()]].concat())
-					}));
-					out.0.insert(resulting_unit, v.clone());
-				}
-				None => {
-					out.0.insert(
-						resulting_unit,
-						LinearCombination(
-							a.0[&a_unit]
-								.clone()
-								.0
-								.into_iter()
-								.map(|(s, term)| {
-									(s * sign * *b_sign, [term, vec![b_unit.clone()]].concat())
-								})
-								.collect::<Vec<_>>(),
-						),
-					);
-				}
+	let mut out = LinearCombinations::zero();
+	for (a_unit, a_sum) in &a.0 {
+		for &(b_sign, b_unit) in b {
+			let sign = reordering_sign(a_unit.0, b_unit.0) * b_sign;
+			let sum = out
+				.0
+				.entry(Element(a_unit.0 ^ b_unit.0))
+				.or_insert_with(LinearCombination::zero);
+			sum.0.reserve(a_sum.0.len());
+			for (term_sign, factors) in &a_sum.0 {
+				let mut term = Vec::with_capacity(factors.len() + 1);
+				term.extend_from_slice(factors);
+				term.push(b_unit);
+				sum.0.push((*term_sign * sign, term));
 			}
 		}
 	}
@@ -132,30 +180,125 @@ pub fn impl_mul_bases(a: &LinearCombinations, b: &[(Sign, Element)]) -> LinearCo
 pub(crate) fn mul_bases(bases: &[&Basis]) -> LinearCombinations {
 	let mut out: LinearCombinations = LinearCombinations::one();
 
-	for b in bases.iter().map(|base| base) {
+	for b in bases.iter() {
 		out = impl_mul_bases(
 			&out,
-			&b.0.iter()
-				.map(|p| (Sign::Pos, p.clone()))
-				.collect::<Vec<_>>(),
+			&b.0.iter().map(|p| (Sign::Pos, *p)).collect::<Vec<_>>(),
 		);
 	}
 	out
 }
 
-/// A vector of the canonical basis
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct Element(pub Vec<u32>);
+/// Terms of the sandwich product `R x ~R`, for a rotor `R` and an `x` of the given basis.
+///
+/// The factors of each term are, in order, from `R`, `x` and `~R`.
+pub(crate) fn sandwich(rotor_basis: &Basis, rhs_basis: &Basis) -> LinearCombinations {
+	let mut result = LinearCombinations::one();
+
+	result = impl_mul_bases(
+		&result,
+		&rotor_basis
+			.0
+			.iter()
+			.map(|r| (Sign::Pos, *r))
+			.collect::<Vec<_>>(),
+	);
+
+	result = impl_mul_bases(
+		&result,
+		&rhs_basis
+			.0
+			.iter()
+			.map(|r| (Sign::Pos, *r))
+			.collect::<Vec<_>>(),
+	);
+
+	impl_mul_bases(
+		&result,
+		&rotor_basis
+			.0
+			.iter()
+			.map(|r| {
+				(
+					match r.grade() / 2 % 2 {
+						0 => Sign::Pos,
+						1 => Sign::Neg,
+						_ => unreachable!("maths broke"),
+					},
+					*r,
+				)
+			})
+			.collect::<Vec<_>>(),
+	)
+}
+
+/// Terms of the wedge product of two k-vectors
+pub(crate) fn wedge(
+	lhs_grade: usize,
+	lhs_basis: &Basis,
+	rhs_grade: usize,
+	rhs_basis: &Basis,
+) -> LinearCombinations {
+	let mut combinations = mul_bases(&[lhs_basis, rhs_basis]);
+
+	combinations
+		.0
+		.retain(|k, _| k.grade() == lhs_grade + rhs_grade);
+
+	combinations
+}
+
+/// Terms of the left contraction of a k-vector onto a higher grade k-vector
+pub(crate) fn left_contraction(
+	lhs_grade: usize,
+	lhs_basis: &Basis,
+	rhs_grade: usize,
+	rhs_basis: &Basis,
+) -> LinearCombinations {
+	let mut combinations = mul_bases(&[lhs_basis, rhs_basis]);
+
+	combinations
+		.0
+		.retain(|k, _| k.grade() + lhs_grade == rhs_grade);
+
+	combinations
+}
+
+/// A vector of the canonical basis, as the bitmask of the basis vectors it is the product of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Element(pub u32);
 impl Element {
+	pub fn from_indices(indices: impl IntoIterator<Item = u32>) -> Self {
+		Self(indices.into_iter().fold(0, |mask, i| mask | 1 << i))
+	}
+
 	pub fn grade(&self) -> usize {
-		self.0.len()
+		self.0.count_ones() as usize
+	}
+
+	/// Indices of the basis vectors, in increasing order
+	pub fn indices(self) -> impl Iterator<Item = u32> {
+		(0..u32::BITS).filter(move |i| self.0 >> i & 1 == 1)
+	}
+}
+
+/// Lexicographic order of the indices, e.g. `e01 < e012 < e02 < e1`
+impl Ord for Element {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.indices().cmp(other.indices())
+	}
+}
+
+impl PartialOrd for Element {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
 	}
 }
 
 impl Display for Element {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		write!(f, "e")?;
-		for v in &self.0 {
+		for v in self.indices() {
 			write!(f, "{}", v)?;
 		}
 		Ok(())
@@ -168,10 +311,10 @@ pub struct Basis(pub Vec<Element>);
 
 impl std::ops::Mul<Element> for Element {
 	type Output = (Sign, Element);
+	#[allow(clippy::suspicious_arithmetic_impl)]
 	fn mul(self, rhs: Element) -> Self::Output {
-		let b = [self.0, rhs.0].concat();
-		let (sign, b) = gnome_sort(&b);
-		(Sign::from(sign), Element(b))
+		// the basis vectors square to 1, so the common ones cancel out
+		(reordering_sign(self.0, rhs.0), Element(self.0 ^ rhs.0))
 	}
 }
 
@@ -194,37 +337,28 @@ impl std::ops::Mul<Sign> for Sign {
 	}
 }
 
-impl From<i32> for Sign {
-	fn from(value: i32) -> Self {
-		match value >= 0 {
-			true => Sign::Pos,
-			false => Sign::Neg,
-		}
-	}
-}
-
 pub(crate) fn element_name(base: &Element) -> Ident {
 	format_ident!(
 		"e{}",
-		base.0.iter().map(|i| i.to_string()).collect::<String>()
+		base.indices().map(|i| i.to_string()).collect::<String>()
 	)
 }
 
 pub(crate) fn element_name_upper(base: &Element) -> Ident {
 	format_ident!(
 		"E{}",
-		base.0.iter().map(|i| i.to_string()).collect::<String>()
+		base.indices().map(|i| i.to_string()).collect::<String>()
 	)
 }
 
 pub(crate) fn basis_names(basis: &Basis) -> Vec<Ident> {
-	basis.0.iter().map(|e| element_name(e)).collect()
+	basis.0.iter().map(element_name).collect()
 }
 
 pub(crate) fn infer<B: IntoIterator<Item = Element>>(basis: B) -> MvKind {
-	let grades: HashSet<usize> = basis.into_iter().map(|e| e.0.len()).collect();
-	let odd_grades: HashSet<usize> = grades.iter().filter(|&x| x % 2 != 0).map(|&x| x).collect();
-	if grades.len() > 1 && odd_grades.len() == 0 {
+	let grades: HashSet<usize> = basis.into_iter().map(|e| e.grade()).collect();
+	let odd_grades: HashSet<usize> = grades.iter().filter(|&x| x % 2 != 0).copied().collect();
+	if grades.len() > 1 && odd_grades.is_empty() {
 		MvKind::Rotor
 	} else if grades.len() > 1 {
 		MvKind::General
@@ -0,0 +1,6 @@
+//! Errors of the macro options.
+
+#[test]
+fn ui() {
+	trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
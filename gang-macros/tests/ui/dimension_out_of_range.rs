@@ -0,0 +1,3 @@
+gang::gang!(9);
+
+fn main() {}
@@ -0,0 +1,3 @@
+gang::gang!(3, grades = [1, 4]);
+
+fn main() {}
@@ -0,0 +1,3 @@
+gang::gang!(3, products = rotate);
+
+fn main() {}
@@ -0,0 +1,5 @@
+gang::gang!(3, products = [geometric, wedge]);
+
+fn main() {
+	let _ = gang::Rotate::rotate(Rot::IDENTITY, V1::E0);
+}
@@ -0,0 +1,5 @@
+gang::gang!(4, products = [rotate, wedge]);
+
+fn main() {
+	let _ = Rot::IDENTITY * Rot::IDENTITY;
+}
@@ -0,0 +1,3 @@
+gang::gang!(3, grade = [1]);
+
+fn main() {}
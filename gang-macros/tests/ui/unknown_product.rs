@@ -0,0 +1,3 @@
+gang::wgsl!(3, products = [rotate, dot]);
+
+fn main() {}
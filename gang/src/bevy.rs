@@ -0,0 +1,228 @@
+//! Bevy integration: N-dimensional transforms and their propagation through the hierarchy.
+//!
+//! Each enabled `gN` feature has a matching module here, e.g. `gang::bevy::g4::TransformN`.
+//! Add [`TransformNPlugin`] to the app to keep every `GlobalTransformN` up to date.
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::schedule::SystemSet;
+
+/// Propagates `TransformN` into `GlobalTransformN`, in `PostUpdate`
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TransformNSystems;
+
+/// Registers the transform propagation systems of every enabled dimension
+pub struct TransformNPlugin;
+
+impl Plugin for TransformNPlugin {
+	#[allow(unused_variables)]
+	fn build(&self, app: &mut App) {
+		#[cfg(feature = "g2")]
+		g2::build(app);
+		#[cfg(feature = "g3")]
+		g3::build(app);
+		#[cfg(feature = "g4")]
+		g4::build(app);
+		#[cfg(feature = "g5")]
+		g5::build(app);
+	}
+}
+
+#[allow(unused_macros)] // without g2..g5
+macro_rules! transform_module {
+	($g:ident) => {
+		pub mod $g {
+			use bevy_app::{App, PostUpdate};
+			use bevy_ecs::{prelude::*, schedule::IntoScheduleConfigs};
+			#[cfg(feature = "bevy_reflect")]
+			use bevy_reflect::std_traits::ReflectDefault;
+
+			use super::TransformNSystems;
+			use crate::$g::{Rot, Rotate, V1};
+
+			/// Local transform of an entity, relative to its parent
+			#[derive(Component, Clone, Copy, PartialEq, Debug)]
+			#[cfg_attr(
+				feature = "bevy_reflect",
+				derive(bevy_reflect::Reflect),
+				reflect(Component, Default, PartialEq, Debug)
+			)]
+			#[require(GlobalTransformN)]
+			pub struct TransformN {
+				pub translation: V1,
+				pub rotation: Rot,
+				pub scale: f32,
+			}
+
+			impl TransformN {
+				pub const IDENTITY: Self = Self {
+					translation: V1::ZERO,
+					rotation: Rot::IDENTITY,
+					scale: 1.0,
+				};
+
+				pub fn from_translation(translation: V1) -> Self {
+					Self {
+						translation,
+						..Self::IDENTITY
+					}
+				}
+
+				pub fn from_rotation(rotation: Rot) -> Self {
+					Self {
+						rotation,
+						..Self::IDENTITY
+					}
+				}
+
+				pub fn from_scale(scale: f32) -> Self {
+					Self {
+						scale,
+						..Self::IDENTITY
+					}
+				}
+
+				pub fn with_translation(self, translation: V1) -> Self {
+					Self {
+						translation,
+						..self
+					}
+				}
+
+				pub fn with_rotation(self, rotation: Rot) -> Self {
+					Self { rotation, ..self }
+				}
+
+				pub fn with_scale(self, scale: f32) -> Self {
+					Self { scale, ..self }
+				}
+
+				/// Scales, then rotates, then translates a point
+				pub fn transform_point(self, point: V1) -> V1 {
+					self.rotation.rotate(point * self.scale) + self.translation
+				}
+
+				/// Transform applying `child` first, then `self`
+				pub fn mul_transform(self, child: TransformN) -> TransformN {
+					TransformN {
+						translation: self.transform_point(child.translation),
+						rotation: self.rotation * child.rotation,
+						scale: self.scale * child.scale,
+					}
+				}
+			}
+
+			impl Default for TransformN {
+				fn default() -> Self {
+					Self::IDENTITY
+				}
+			}
+
+			/// Transform of an entity relative to the world, computed from the hierarchy
+			#[derive(Component, Clone, Copy, PartialEq, Debug, Default)]
+			#[cfg_attr(
+				feature = "bevy_reflect",
+				derive(bevy_reflect::Reflect),
+				reflect(Component, Default, PartialEq, Debug)
+			)]
+			pub struct GlobalTransformN(TransformN);
+
+			impl GlobalTransformN {
+				pub const IDENTITY: Self = Self(TransformN::IDENTITY);
+
+				pub fn translation(&self) -> V1 {
+					self.0.translation
+				}
+
+				pub fn rotation(&self) -> Rot {
+					self.0.rotation
+				}
+
+				pub fn scale(&self) -> f32 {
+					self.0.scale
+				}
+
+				pub fn to_transform(self) -> TransformN {
+					self.0
+				}
+
+				pub fn transform_point(self, point: V1) -> V1 {
+					self.0.transform_point(point)
+				}
+
+				pub fn mul_transform(self, child: TransformN) -> GlobalTransformN {
+					Self(self.0.mul_transform(child))
+				}
+			}
+
+			impl From<TransformN> for GlobalTransformN {
+				fn from(transform: TransformN) -> Self {
+					Self(transform)
+				}
+			}
+
+			/// Updates the `GlobalTransformN` of every entity, starting from the roots of the hierarchy.
+			///
+			/// Like `bevy_transform`, only the subtrees below a changed `TransformN` or `ChildOf`
+			/// are recomputed.
+			pub fn propagate_transforms(
+				mut roots: Query<(Entity, Ref<TransformN>, &mut GlobalTransformN), Without<ChildOf>>,
+				mut orphaned: RemovedComponents<ChildOf>,
+				children: Query<&Children>,
+				mut descendants: Query<(Ref<TransformN>, Ref<ChildOf>, &mut GlobalTransformN)>,
+			) {
+				let orphaned = orphaned.read().collect::<bevy_ecs::entity::EntityHashSet>();
+				for (entity, transform, mut global) in &mut roots {
+					let changed = transform.is_changed() || orphaned.contains(&entity);
+					if changed {
+						*global = GlobalTransformN::from(*transform);
+					}
+					propagate_recursive(*global, changed, entity, &children, &mut descendants);
+				}
+			}
+
+			fn propagate_recursive(
+				parent: GlobalTransformN,
+				parent_changed: bool,
+				entity: Entity,
+				children: &Query<&Children>,
+				descendants: &mut Query<(Ref<TransformN>, Ref<ChildOf>, &mut GlobalTransformN)>,
+			) {
+				let Ok(entity_children) = children.get(entity) else {
+					return;
+				};
+				for &child in entity_children {
+					let Ok((transform, child_of, mut global)) = descendants.get_mut(child) else {
+						continue;
+					};
+					let changed = parent_changed || transform.is_changed() || child_of.is_changed();
+					if changed {
+						*global = parent.mul_transform(*transform);
+					}
+					let global = *global;
+					propagate_recursive(global, changed, child, children, descendants);
+				}
+			}
+
+			pub(super) fn build(app: &mut App) {
+				app.add_systems(PostUpdate, propagate_transforms.in_set(TransformNSystems));
+				#[cfg(feature = "bevy_reflect")]
+				{
+					app.register_type::<TransformN>()
+						.register_type::<GlobalTransformN>();
+					crate::$g::register_types(
+						&mut app.world().resource::<AppTypeRegistry>().write(),
+					);
+				}
+			}
+		}
+	};
+}
+
+#[cfg(feature = "g2")]
+transform_module!(g2);
+#[cfg(feature = "g3")]
+transform_module!(g3);
+#[cfg(feature = "g4")]
+transform_module!(g4);
+#[cfg(feature = "g5")]
+transform_module!(g5);
@@ -0,0 +1,27 @@
+//! Random distributions for the types of every `gN` module.
+//!
+//! `StandardUniform` is also implemented: k-vectors and `Mv` get components in `[0, 1)`,
+//! and rotors are uniformly distributed rotations like with [`UniformRotor`].
+
+use rand::Rng;
+
+/// Uniformly distributed unit `V1`, i.e. directions
+#[derive(Clone, Copy, Debug)]
+pub struct UnitSphere;
+
+/// Uniformly distributed unit simple `V2`, i.e. oriented planes
+#[derive(Clone, Copy, Debug)]
+pub struct UnitSimpleBivector;
+
+/// Uniformly distributed unit `Rot`, following the Haar measure of SO(N)
+#[derive(Clone, Copy, Debug)]
+pub struct UniformRotor;
+
+/// Sample of the standard normal distribution, using the Box-Muller transform
+#[doc(hidden)]
+pub fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f32 {
+	// in (0, 1]
+	let u = 1.0 - rng.random::<f32>();
+	let v = rng.random::<f32>();
+	crate::math::box_muller(u, v)
+}
@@ -0,0 +1,552 @@
+//! Multivectors whose dimension and signature are chosen at runtime.
+//!
+//! Blades are bitmasks of their basis vectors, e.g. `0b101` is `e02`, and a
+//! [`Multivector`] stores one coefficient for each of the `2^dim` blades. This
+//! is slower than the types of the `gN` modules, but works in any dimension
+//! and metric.
+
+use alloc::{vec, vec::Vec};
+use core::{
+	fmt,
+	ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::Wedge;
+
+/// Square of each basis vector, as bitmasks of the negative and null ones
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Signature {
+	dim: u32,
+	negative: u32,
+	null: u32,
+}
+
+impl Signature {
+	/// Basis vectors that all square to 1
+	pub fn euclidean(dim: u32) -> Self {
+		Self::new(dim, 0, 0)
+	}
+
+	/// `p` basis vectors squaring to 1, then `q` to -1, then `r` to 0
+	pub fn new(p: u32, q: u32, r: u32) -> Self {
+		let dim = p
+			.checked_add(q)
+			.and_then(|pq| pq.checked_add(r))
+			.filter(|&dim| dim < 32)
+			.unwrap_or_else(|| panic!("dimension {p} + {q} + {r} is too high"));
+		Self {
+			dim,
+			negative: ((1 << q) - 1) << p,
+			null: ((1 << r) - 1) << (p + q),
+		}
+	}
+
+	/// Signature where the basis vector `i` squares to `squares[i]`, which must be 1, -1 or 0
+	pub fn from_squares(squares: &[i8]) -> Self {
+		let dim = squares.len() as u32;
+		assert!(dim < 32, "dimension {dim} is too high");
+		let (mut negative, mut null) = (0, 0);
+		for (i, square) in squares.iter().enumerate() {
+			match square {
+				1 => {}
+				-1 => negative |= 1 << i,
+				0 => null |= 1 << i,
+				_ => panic!("basis vectors must square to 1, -1 or 0, not {square}"),
+			}
+		}
+		Self {
+			dim,
+			negative,
+			null,
+		}
+	}
+
+	pub fn dim(self) -> u32 {
+		self.dim
+	}
+
+	/// Number of blades, `2^dim`
+	pub fn blade_count(self) -> usize {
+		1 << self.dim
+	}
+
+	/// Bitmask of the pseudoscalar
+	pub fn pseudoscalar(self) -> u32 {
+		(1 << self.dim) - 1
+	}
+
+	/// Sign and blade of the geometric product of two blades
+	pub fn blade_product(self, lhs: u32, rhs: u32) -> (f32, u32) {
+		let common = lhs & rhs;
+		if common & self.null != 0 {
+			return (0.0, lhs ^ rhs);
+		}
+		let sign = reordering_sign(lhs, rhs) * parity_sign((common & self.negative).count_ones());
+		(sign, lhs ^ rhs)
+	}
+}
+
+/// Sign of the reordering of the basis vectors of `lhs` followed by those of
+/// `rhs` into increasing order, the same as counting the swaps of `gnome_sort`
+pub fn reordering_sign(lhs: u32, rhs: u32) -> f32 {
+	gang_blade::reordering_sign(lhs, rhs) as f32
+}
+
+fn parity_sign(n: u32) -> f32 {
+	if n % 2 == 0 {
+		1.0
+	} else {
+		-1.0
+	}
+}
+
+/// Grade of a blade, its number of basis vectors
+pub fn grade(blade: u32) -> u32 {
+	blade.count_ones()
+}
+
+/// Multivector of any dimension and signature, with one coefficient per blade
+#[derive(Clone, PartialEq, Debug)]
+pub struct Multivector {
+	signature: Signature,
+	coefficients: Vec<f32>,
+}
+
+impl Multivector {
+	pub fn zero(signature: Signature) -> Self {
+		Self {
+			signature,
+			coefficients: vec![0.0; signature.blade_count()],
+		}
+	}
+
+	pub fn scalar(signature: Signature, value: f32) -> Self {
+		Self::blade(signature, 0, value)
+	}
+
+	/// Basis vector `e{i}`
+	pub fn basis_vector(signature: Signature, i: u32) -> Self {
+		assert!(
+			i < signature.dim,
+			"no basis vector e{i} in dimension {}",
+			signature.dim
+		);
+		Self::blade(signature, 1 << i, 1.0)
+	}
+
+	/// `value` times the blade with the bitmask `blade`
+	pub fn blade(signature: Signature, blade: u32, value: f32) -> Self {
+		let mut mv = Self::zero(signature);
+		mv[blade] = value;
+		mv
+	}
+
+	pub fn pseudoscalar(signature: Signature) -> Self {
+		Self::blade(signature, signature.pseudoscalar(), 1.0)
+	}
+
+	/// Coefficients indexed by blade bitmask
+	pub fn from_coefficients(signature: Signature, coefficients: Vec<f32>) -> Self {
+		assert_eq!(coefficients.len(), signature.blade_count());
+		Self {
+			signature,
+			coefficients,
+		}
+	}
+
+	pub fn signature(&self) -> Signature {
+		self.signature
+	}
+
+	pub fn coefficients(&self) -> &[f32] {
+		&self.coefficients
+	}
+
+	/// Non-zero blades and their coefficients
+	pub fn terms(&self) -> impl Iterator<Item = (u32, f32)> + '_ {
+		self.coefficients
+			.iter()
+			.enumerate()
+			.filter(|(_, c)| **c != 0.0)
+			.map(|(blade, c)| (blade as u32, *c))
+	}
+
+	/// Part of grade `k`
+	pub fn grade(&self, k: u32) -> Self {
+		self.map_blades(|blade, c| if grade(blade) == k { c } else { 0.0 })
+	}
+
+	/// Reverses the order of the basis vectors of each blade
+	pub fn reverse(&self) -> Self {
+		self.map_blades(|blade, c| {
+			let k = grade(blade);
+			c * parity_sign(k * k.saturating_sub(1) / 2)
+		})
+	}
+
+	/// Flips the sign of the odd blades
+	pub fn involute(&self) -> Self {
+		self.map_blades(|blade, c| c * parity_sign(grade(blade)))
+	}
+
+	/// Right complement, mapping each blade `a` to the blade `b` such that `a ∧ b` is the pseudoscalar.
+	///
+	/// It doesn't depend on the metric, so it is also defined for degenerate signatures.
+	pub fn dual(&self) -> Self {
+		let pseudoscalar = self.signature.pseudoscalar();
+		let mut out = Self::zero(self.signature);
+		for (blade, c) in self.terms() {
+			let complement = pseudoscalar ^ blade;
+			out[complement] = c * reordering_sign(blade, complement);
+		}
+		out
+	}
+
+	/// Inverse of `dual`
+	pub fn undual(&self) -> Self {
+		let pseudoscalar = self.signature.pseudoscalar();
+		let mut out = Self::zero(self.signature);
+		for (blade, c) in self.terms() {
+			let complement = pseudoscalar ^ blade;
+			out[complement] = c * reordering_sign(complement, blade);
+		}
+		out
+	}
+
+	/// Geometric product
+	pub fn geometric(&self, rhs: &Self) -> Self {
+		self.product(rhs, |_, _| true)
+	}
+
+	/// Outer product, the part of the geometric product where the blades share no vector
+	pub fn wedge(&self, rhs: &Self) -> Self {
+		self.product(rhs, |a, b| a & b == 0)
+	}
+
+	/// Left contraction `self ⌋ rhs`, of grade `grade(rhs) - grade(self)`
+	pub fn left_contraction(&self, rhs: &Self) -> Self {
+		self.product(rhs, |a, b| a & b == a)
+	}
+
+	/// Right contraction `self ⌊ rhs`, of grade `grade(self) - grade(rhs)`
+	pub fn right_contraction(&self, rhs: &Self) -> Self {
+		self.product(rhs, |a, b| a & b == b)
+	}
+
+	/// Inner product, of grade `|grade(self) - grade(rhs)|`
+	pub fn inner(&self, rhs: &Self) -> Self {
+		self.product(rhs, |a, b| a & b == a || a & b == b)
+	}
+
+	/// Scalar part of the geometric product
+	pub fn scalar_product(&self, rhs: &Self) -> f32 {
+		self.product(rhs, |a, b| a == b)[0]
+	}
+
+	pub fn norm_squared(&self) -> f32 {
+		self.scalar_product(&self.reverse())
+	}
+
+	pub fn norm(&self) -> f32 {
+		crate::math::sqrt(crate::math::abs(self.norm_squared()))
+	}
+
+	/// Sum of the geometric products of the blade pairs selected by `filter`
+	fn product(&self, rhs: &Self, filter: impl Fn(u32, u32) -> bool) -> Self {
+		assert_eq!(self.signature, rhs.signature, "mismatched signatures");
+		let mut out = Self::zero(self.signature);
+		for (a, lhs_c) in self.terms() {
+			for (b, rhs_c) in rhs.terms() {
+				if filter(a, b) {
+					let (sign, blade) = self.signature.blade_product(a, b);
+					out[blade] += sign * lhs_c * rhs_c;
+				}
+			}
+		}
+		out
+	}
+
+	fn map_blades(&self, f: impl Fn(u32, f32) -> f32) -> Self {
+		Self {
+			signature: self.signature,
+			coefficients: self
+				.coefficients
+				.iter()
+				.enumerate()
+				.map(|(blade, c)| f(blade as u32, *c))
+				.collect(),
+		}
+	}
+
+	fn zip(&self, rhs: &Self, f: impl Fn(f32, f32) -> f32) -> Self {
+		assert_eq!(self.signature, rhs.signature, "mismatched signatures");
+		Self {
+			signature: self.signature,
+			coefficients: self
+				.coefficients
+				.iter()
+				.zip(&rhs.coefficients)
+				.map(|(a, b)| f(*a, *b))
+				.collect(),
+		}
+	}
+}
+
+impl Index<u32> for Multivector {
+	type Output = f32;
+	fn index(&self, blade: u32) -> &f32 {
+		&self.coefficients[blade as usize]
+	}
+}
+
+impl IndexMut<u32> for Multivector {
+	fn index_mut(&mut self, blade: u32) -> &mut f32 {
+		&mut self.coefficients[blade as usize]
+	}
+}
+
+impl fmt::Display for Multivector {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mut empty = true;
+		for (blade, c) in self.terms() {
+			if !empty {
+				f.write_str(" + ")?;
+			}
+			empty = false;
+			fmt::Display::fmt(&c, f)?;
+			if blade != 0 {
+				// `*` keeps `2e01` from reading as an exponent
+				f.write_str("*e")?;
+				for i in 0..self.signature.dim {
+					if blade & (1 << i) != 0 {
+						write!(f, "{i}")?;
+					}
+				}
+			}
+		}
+		if empty {
+			f.write_str("0")?;
+		}
+		Ok(())
+	}
+}
+
+impl Mul<&Multivector> for &Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: &Multivector) -> Multivector {
+		self.geometric(rhs)
+	}
+}
+
+impl Mul for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: Multivector) -> Multivector {
+		self.geometric(&rhs)
+	}
+}
+
+impl Mul<f32> for &Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: f32) -> Multivector {
+		self.map_blades(|_, c| c * rhs)
+	}
+}
+
+impl Mul<f32> for Multivector {
+	type Output = Multivector;
+	fn mul(self, rhs: f32) -> Multivector {
+		&self * rhs
+	}
+}
+
+impl Wedge<&Multivector> for &Multivector {
+	type Output = Multivector;
+	fn wedge(self, rhs: &Multivector) -> Multivector {
+		Multivector::wedge(self, rhs)
+	}
+}
+
+impl Add<&Multivector> for &Multivector {
+	type Output = Multivector;
+	fn add(self, rhs: &Multivector) -> Multivector {
+		self.zip(rhs, |a, b| a + b)
+	}
+}
+
+impl Add for Multivector {
+	type Output = Multivector;
+	fn add(self, rhs: Multivector) -> Multivector {
+		&self + &rhs
+	}
+}
+
+impl AddAssign<&Multivector> for Multivector {
+	fn add_assign(&mut self, rhs: &Multivector) {
+		*self = &*self + rhs;
+	}
+}
+
+impl Sub<&Multivector> for &Multivector {
+	type Output = Multivector;
+	fn sub(self, rhs: &Multivector) -> Multivector {
+		self.zip(rhs, |a, b| a - b)
+	}
+}
+
+impl Sub for Multivector {
+	type Output = Multivector;
+	fn sub(self, rhs: Multivector) -> Multivector {
+		&self - &rhs
+	}
+}
+
+impl SubAssign<&Multivector> for Multivector {
+	fn sub_assign(&mut self, rhs: &Multivector) {
+		*self = &*self - rhs;
+	}
+}
+
+impl Neg for &Multivector {
+	type Output = Multivector;
+	fn neg(self) -> Multivector {
+		self.map_blades(|_, c| -c)
+	}
+}
+
+impl Neg for Multivector {
+	type Output = Multivector;
+	fn neg(self) -> Multivector {
+		-&self
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const SIGNATURES: [(u32, u32, u32); 6] = [
+		(3, 0, 0),
+		(4, 0, 0),
+		(2, 1, 0),
+		(1, 3, 0),
+		(3, 0, 1),
+		(2, 1, 2),
+	];
+
+	/// Multivectors with every blade, from a linear congruential generator
+	fn samples(signature: Signature, count: usize) -> Vec<Multivector> {
+		let mut state = 0x2545_f491_u32;
+		(0..count)
+			.map(|_| {
+				let coefficients = (0..signature.blade_count())
+					.map(|_| {
+						state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+						(state >> 8) as f32 / (1 << 24) as f32 * 4.0 - 2.0
+					})
+					.collect();
+				Multivector::from_coefficients(signature, coefficients)
+			})
+			.collect()
+	}
+
+	fn assert_close(lhs: &Multivector, rhs: &Multivector) {
+		let close = lhs
+			.coefficients()
+			.iter()
+			.zip(rhs.coefficients())
+			.all(|(a, b)| (a - b).abs() < 1e-3);
+		assert!(close, "{lhs} != {rhs}");
+	}
+
+	#[test]
+	fn geometric_product_is_associative() {
+		for (p, q, r) in SIGNATURES {
+			let signature = Signature::new(p, q, r);
+			let mvs = samples(signature, 3);
+			let (a, b, c) = (&mvs[0], &mvs[1], &mvs[2]);
+			assert_close(&(&(a * b) * c), &(a * &(b * c)));
+		}
+	}
+
+	#[test]
+	fn null_vectors_square_to_zero() {
+		let signature = Signature::new(1, 1, 1);
+		let squares = (0..3)
+			.map(|i| {
+				let e = Multivector::basis_vector(signature, i);
+				(&e * &e)[0]
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(squares, [1.0, -1.0, 0.0]);
+	}
+
+	#[test]
+	fn contractions() {
+		for (p, q, r) in SIGNATURES {
+			let signature = Signature::new(p, q, r);
+			let mvs = samples(signature, 4);
+			let (a, b, c) = (&mvs[0], &mvs[1], &mvs[2]);
+
+			// (a ∧ b) ⌋ c = a ⌋ (b ⌋ c) and c ⌊ (b ∧ a) = (c ⌊ b) ⌊ a
+			assert_close(
+				&a.wedge(b).left_contraction(c),
+				&a.left_contraction(&b.left_contraction(c)),
+			);
+			assert_close(
+				&c.right_contraction(&b.wedge(a)),
+				&c.right_contraction(b).right_contraction(a),
+			);
+
+			// for a vector v, v ⌋ x = (v x - x̂ v) / 2 and x ⌊ v = (x v - v x̂) / 2
+			let v = mvs[3].grade(1);
+			assert_close(
+				&v.left_contraction(c),
+				&((&(&v * c) - &(&c.involute() * &v)) * 0.5),
+			);
+			assert_close(
+				&c.right_contraction(&v),
+				&((&(c * &v) - &(&v * &c.involute())) * 0.5),
+			);
+
+			// the inner product of blades is the contraction onto the higher grade
+			let (a2, b3) = (a.grade(2), b.grade(3));
+			assert_close(&a2.inner(&b3), &a2.left_contraction(&b3));
+		}
+	}
+
+	#[test]
+	fn dual_round_trip() {
+		for (p, q, r) in SIGNATURES {
+			let signature = Signature::new(p, q, r);
+			let x = &samples(signature, 1)[0];
+			assert_eq!(&x.dual().undual(), x);
+			assert_eq!(&x.undual().dual(), x);
+
+			// each blade wedged with its dual is the pseudoscalar
+			for blade in 0..signature.blade_count() as u32 {
+				let b = Multivector::blade(signature, blade, 1.0);
+				assert_eq!(b.wedge(&b.dual()), Multivector::pseudoscalar(signature));
+			}
+		}
+	}
+
+	#[test]
+	fn reverse_and_involute_signs() {
+		let signature = Signature::euclidean(5);
+		let x = Multivector::from_coefficients(signature, vec![1.0; signature.blade_count()]);
+		let (reverse, involute) = (x.reverse(), x.involute());
+		for k in 0..=5 {
+			let blade = (1 << k) - 1;
+			let expected_reverse = [1.0, 1.0, -1.0, -1.0, 1.0, 1.0][k as usize];
+			let expected_involute = [1.0, -1.0, 1.0, -1.0, 1.0, -1.0][k as usize];
+			assert_eq!(reverse[blade], expected_reverse, "grade {k}");
+			assert_eq!(involute[blade], expected_involute, "grade {k}");
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "too high")]
+	fn signature_overflow() {
+		Signature::new(u32::MAX, 1, 1);
+	}
+}
@@ -1,66 +1,298 @@
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::{
+	fmt::Debug,
+	ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
 pub trait Rotate<Rhs = Self> {
 	type Output;
 
 	fn rotate(self, rhs: Rhs) -> Self::Output;
 }
 
+/// Rotates many elements at once, deriving the rotation matrix only once
+pub trait RotateBatch<Rhs> {
+	fn rotate_slice(&self, rhs: &mut [Rhs]);
+
+	fn rotate_iter<I: IntoIterator<Item = Rhs>>(&self, rhs: I) -> impl Iterator<Item = Rhs>;
+}
+
 pub trait Wedge<Rhs = Self> {
 	type Output;
 
 	fn wedge(self, rhs: Rhs) -> Self::Output;
 }
 
-pub use gang_macros::gang;
+pub trait Norm {
+	fn norm(self) -> f32;
 
-#[cfg(feature = "g2")]
-pub mod g2 {
-	use crate as gang;
-	pub use crate::prelude::*;
-	gang_macros::gang!(2);
+	fn normalize(self) -> Self;
+}
 
-	#[cfg(feature = "mint")]
-	mod mint_impl {
-		use super::*;
-		impl From<V1> for mint::Vector2<f64> {
-			fn from(v: V1) -> Self {
-				Self {
-					x: v.e0 as f64,
-					y: v.e1 as f64,
-				}
-			}
-		}
-		impl From<mint::Vector2<f64>> for V1 {
-			fn from(v: mint::Vector2<f64>) -> Self {
-				Self {
-					e0: v.x as f32,
-					e1: v.y as f32,
-				}
+/// Operations shared by the k-vectors of every dimension
+pub trait Vector:
+	Copy
+	+ Debug
+	+ Default
+	+ PartialEq
+	+ Add<Output = Self>
+	+ Sub<Output = Self>
+	+ Neg<Output = Self>
+	+ Mul<f32, Output = Self>
+	+ AddAssign
+	+ SubAssign
+	+ Norm
+{
+	const ZERO: Self;
+
+	fn normalize_or_zero(self) -> Self;
+
+	fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool;
+}
+
+/// Operations shared by the rotors of every dimension
+pub trait Rotor:
+	Copy + Debug + Default + PartialEq + Mul<Output = Self> + Mul<f32, Output = Self> + Norm
+{
+	/// The bivectors of the rotation planes
+	type V2: Vector;
+
+	const IDENTITY: Self;
+
+	fn from_v2_angle(v2: Self::V2, angle: f32) -> Self;
+
+	fn abs_diff_eq_up_to_sign(self, rhs: Self, max_abs_diff: f32) -> bool;
+}
+
+/// The types of a `gN` module, to write code once for every dimension.
+///
+/// Each module has a unit struct `Algebra` implementing it:
+///
+/// ```
+/// # #[cfg(feature = "g3")] {
+/// use gang::{Norm, Rotate, Rotor};
+///
+/// struct Body<A: gang::Algebra> {
+///     position: A::V1,
+///     orientation: A::Rot,
+/// }
+///
+/// impl<A: gang::Algebra> Body<A> {
+///     fn spin(&mut self, plane: A::V2, angle: f32) {
+///         self.orientation = A::Rot::from_v2_angle(plane, angle) * self.orientation;
+///     }
+///
+///     fn forward(&self, axis: A::V1) -> A::V1 {
+///         self.orientation.rotate(axis).normalize()
+///     }
+/// }
+///
+/// let body = Body::<gang::g3::Algebra> {
+///     position: gang::g3::V1::ZERO,
+///     orientation: gang::g3::Rot::IDENTITY,
+/// };
+/// assert_eq!(body.forward(gang::g3::V1::E0), gang::g3::V1::E0);
+/// # }
+/// ```
+///
+/// `g1`, which has no rotation plane, and modules generated without the core products
+/// (`Rot * Rot`, rotating `V1` and the wedge of two `V1`) don't have it.
+pub trait Algebra: Copy + Debug + Default + 'static {
+	/// The number of dimensions
+	const DIM: usize;
+
+	type V1: Vector + Wedge<Self::V1, Output = Self::V2>;
+	type V2: Vector;
+	type Rot: Rotor<V2 = Self::V2> + Rotate<Self::V1, Output = Self::V1>;
+}
+
+/// The dimension `N` as a type, for code generic over a const dimension.
+///
+/// `G<N>` is the [`Algebra`] of the `gN` module when its feature is enabled, and [`V1<N>`],
+/// [`V2<N>`] and [`Rot<N>`] are its types:
+///
+/// ```
+/// # #[cfg(feature = "g3")] {
+/// use gang::{Algebra, Norm, Vector, G, V1};
+///
+/// fn longest<const N: usize>(vectors: &[V1<N>]) -> V1<N>
+/// where
+///     G<N>: Algebra,
+/// {
+///     let mut longest = V1::<N>::ZERO;
+///     for &v in vectors {
+///         if v.norm() > longest.norm() {
+///             longest = v;
+///         }
+///     }
+///     longest
+/// }
+///
+/// let v: gang::g3::V1 = longest::<3>(&[gang::g3::V1::E0, gang::g3::V1::ONE]);
+/// assert_eq!(v, gang::g3::V1::ONE);
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct G<const N: usize>;
+
+/// The vectors of `gN`
+pub type V1<const N: usize> = <G<N> as Algebra>::V1;
+/// The bivectors of `gN`
+pub type V2<const N: usize> = <G<N> as Algebra>::V2;
+/// The rotors of `gN`
+pub type Rot<const N: usize> = <G<N> as Algebra>::Rot;
+
+macro_rules! impl_dimension {
+	($($feature:literal => $module:ident, $dim:literal;)*) => {
+		$(
+			#[cfg(feature = $feature)]
+			impl Algebra for G<$dim> {
+				const DIM: usize = $dim;
+
+				type V1 = $module::V1;
+				type V2 = $module::V2;
+				type Rot = $module::Rot;
 			}
-		}
+		)*
+	};
+}
+
+impl_dimension! {
+	"g2" => g2, 2;
+	"g3" => g3, 3;
+	"g4" => g4, 4;
+	"g5" => g5, 5;
+	"g6" => g6, 6;
+	"g7" => g7, 7;
+}
+
+pub use gang_macros::{gang, glsl, wgsl};
+
+#[doc(hidden)]
+pub mod math;
+
+/// The crates of the enabled integrations, which the code generated by `gang!` refers to, so
+/// that the invoking crate doesn't need them as dependencies
+#[doc(hidden)]
+pub mod __private {
+	#[cfg(feature = "approx")]
+	pub use approx;
+	#[cfg(feature = "bevy_reflect")]
+	pub use bevy_reflect;
+	/// Glob-imported by the generated modules for the `Reflect` derive, so that the items of the
+	/// invoking module take precedence
+	#[cfg(feature = "bevy_reflect")]
+	pub mod reflect {
+		pub use bevy_reflect::{
+			self, std_traits::ReflectDefault, ReflectDeserialize, ReflectSerialize,
+		};
 	}
+	#[cfg(feature = "bytemuck")]
+	pub use bytemuck;
+	#[cfg(feature = "glam")]
+	pub use glam;
+	#[cfg(feature = "mint")]
+	pub use mint;
+	#[cfg(feature = "nalgebra")]
+	pub use nalgebra;
+	#[cfg(feature = "proptest")]
+	pub use proptest;
+	#[cfg(feature = "rand")]
+	pub use rand;
+	#[cfg(feature = "serde")]
+	pub use serde;
 }
 
-#[cfg(feature = "g3")]
-pub mod g3 {
-	use crate as gang;
-	pub use crate::prelude::*;
-	gang_macros::gang!(3);
+#[cfg(feature = "alloc")]
+pub mod dynamic;
+
+#[cfg(feature = "serde")]
+pub mod serde_seq;
+
+#[cfg(feature = "bevy")]
+pub mod bevy;
+
+#[cfg(feature = "rand")]
+pub mod distr;
+
+#[cfg(feature = "proptest")]
+pub mod testing;
+
+macro_rules! algebra_modules {
+	($($(#[$doc:meta])* $feature:literal => $module:ident, $dim:literal $(, $shaders:ident)?;)*) => {
+		$(
+			$(#[$doc])*
+			#[cfg(feature = $feature)]
+			pub mod $module {
+				use crate as gang;
+				pub use crate::prelude::*;
+				gang_macros::gang!($dim);
+
+				$(shader_sources!($shaders, $dim);)?
+			}
+		)*
+	};
 }
 
-#[cfg(feature = "g4")]
-pub mod g4 {
-	use crate as gang;
-	pub use crate::prelude::*;
-	gang_macros::gang!(4);
+/// The `WGSL` and `GLSL` consts, only up to 5 dimensions like the other GPU types.
+///
+/// Their structs are unpadded, so they match the `Std430` layout of storage buffers, not the
+/// `Std140` mirrors of uniform buffers.
+#[allow(unused_macros)] // without any gN feature
+macro_rules! shader_sources {
+	(shaders, $dim:literal) => {
+		/// WGSL source of the types of this module, and of the products it implements
+		/// (`rot_mul_rot`, `rot_rotate_vK` and `vI_wedge_vJ`)
+		///
+		/// The structs have the std430 layout of storage buffers, i.e. the layout of the Rust types.
+		pub const WGSL: &str = gang_macros::wgsl!($dim);
+		/// GLSL source of the types of this module, and of the products it implements
+		/// (`rot_mul_rot`, `rot_rotate_vK` and `vI_wedge_vJ`)
+		///
+		/// The structs have the std430 layout of storage buffers, i.e. the layout of the Rust types.
+		pub const GLSL: &str = gang_macros::glsl!($dim);
+	};
 }
 
-#[cfg(feature = "g5")]
-pub mod g5 {
-	use crate as gang;
-	pub use crate::prelude::*;
-	gang_macros::gang!(5);
+algebra_modules! {
+	/// The algebra of the line.
+	///
+	/// There is no plane to rotate in, so there is no `V2`, `Rot` only has a scalar part and
+	/// there is no `Algebra` struct.
+	"g1" => g1, 1, shaders;
+	/// The algebra of the plane, where `Rot` works like a unit complex number, with
+	/// `Rot::from_angle` and `Rot::angle`.
+	"g2" => g2, 2, shaders;
+	/// The algebra of space, where `Rot` works like a unit quaternion.
+	"g3" => g3, 3, shaders;
+	/// The algebra of 4D space, where `Rot` has an `e0123` part, so that it can rotate in two
+	/// orthogonal planes at once.
+	"g4" => g4, 4, shaders;
+	/// The algebra of 5D space, the highest dimension with `*`, `Rotate` and `Wedge` for every
+	/// type by default.
+	"g5" => g5, 5, shaders;
+	/// The algebra of 6D space.
+	///
+	/// Only the core products are generated by default: `Rot * Rot`, `V1 * Rot`, `Rot * V1`,
+	/// `Mv * Mv`, rotating `V1` and the wedge of two `V1`. The `g6_geometric`, `g6_rotate` and `g6_wedge` features add the others.
+	/// There are no structure-of-arrays types, std140/std430 mirrors nor shader sources.
+	"g6" => g6, 6;
+	/// The algebra of 7D space.
+	///
+	/// Like `g6`, only the core products and types are generated by default. The `g7_geometric`,
+	/// `g7_rotate` and `g7_wedge` features add the others, `g7_rotate` being the slowest to
+	/// compile.
+	"g7" => g7, 7;
 }
 
+#[allow(unused_imports)]
 mod prelude {
-	pub use super::{Rotate, Wedge};
+	pub use super::{Rotate, RotateBatch, Wedge};
 }
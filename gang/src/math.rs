@@ -0,0 +1,90 @@
+//! Float functions that aren't available in `core`.
+//!
+//! They are taken from `libm` when the feature is enabled, and from `std` otherwise.
+//!
+//! The `deterministic` feature forces the software implementations of `libm`,
+//! so that results are bit-identical on every target.
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("gang requires either the `std` or the `libm` feature");
+
+#[cfg(feature = "libm")]
+mod imp {
+	pub fn abs(x: f32) -> f32 {
+		libm::fabsf(x)
+	}
+
+	pub fn sqrt(x: f32) -> f32 {
+		libm::sqrtf(x)
+	}
+
+	pub fn sin_cos(x: f32) -> (f32, f32) {
+		libm::sincosf(x)
+	}
+
+	pub fn atan2(y: f32, x: f32) -> f32 {
+		libm::atan2f(y, x)
+	}
+
+	pub fn ln(x: f32) -> f32 {
+		libm::logf(x)
+	}
+
+	pub fn floor(x: f32) -> f32 {
+		libm::floorf(x)
+	}
+
+	pub fn round(x: f32) -> f32 {
+		libm::roundf(x)
+	}
+
+	pub fn ceil(x: f32) -> f32 {
+		libm::ceilf(x)
+	}
+}
+
+#[cfg(all(feature = "std", not(feature = "libm")))]
+mod imp {
+	pub fn abs(x: f32) -> f32 {
+		x.abs()
+	}
+
+	pub fn sqrt(x: f32) -> f32 {
+		x.sqrt()
+	}
+
+	pub fn sin_cos(x: f32) -> (f32, f32) {
+		x.sin_cos()
+	}
+
+	pub fn atan2(y: f32, x: f32) -> f32 {
+		y.atan2(x)
+	}
+
+	pub fn ln(x: f32) -> f32 {
+		x.ln()
+	}
+
+	pub fn floor(x: f32) -> f32 {
+		x.floor()
+	}
+
+	pub fn round(x: f32) -> f32 {
+		x.round()
+	}
+
+	pub fn ceil(x: f32) -> f32 {
+		x.ceil()
+	}
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use imp::*;
+
+/// Sample of the standard normal distribution from two uniform samples, `u` in `(0, 1]` (so
+/// that the logarithm is finite) and `v` in `[0, 1)`, using the Box-Muller transform
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn box_muller(u: f32, v: f32) -> f32 {
+	let (s, _) = sin_cos(core::f32::consts::TAU * v);
+	sqrt(-2.0 * ln(u)) * s
+}
@@ -0,0 +1,60 @@
+//! Compact sequence form for serde.
+//!
+//! The generated types serialize as maps of named components by default,
+//! e.g. `{"e0": 1.0, "e1": 2.0}`. Use `#[serde(with = "gang::serde_seq")]`
+//! on a field to store it as a sequence instead, e.g. `[1.0, 2.0]`.
+
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+	de::{self, SeqAccess, Visitor},
+	ser::SerializeTuple,
+	Deserializer, Serializer,
+};
+
+/// Types that can be stored as a sequence of their components
+pub trait Components: Sized + Default {
+	type Array: AsRef<[f32]> + AsMut<[f32]>;
+
+	fn to_components(&self) -> Self::Array;
+
+	fn from_components(arr: Self::Array) -> Self;
+}
+
+/// Stored as a tuple, like arrays, so that the length is known to the format
+pub fn serialize<T: Components, S: Serializer>(v: &T, serializer: S) -> Result<S::Ok, S::Error> {
+	let components = v.to_components();
+	let mut tuple = serializer.serialize_tuple(components.as_ref().len())?;
+	for component in components.as_ref() {
+		tuple.serialize_element(component)?;
+	}
+	tuple.end()
+}
+
+pub fn deserialize<'de, T: Components, D: Deserializer<'de>>(
+	deserializer: D,
+) -> Result<T, D::Error> {
+	let len = T::default().to_components().as_ref().len();
+	deserializer.deserialize_tuple(len, ComponentsVisitor(PhantomData))
+}
+
+struct ComponentsVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Components> Visitor<'de> for ComponentsVisitor<T> {
+	type Value = T;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let len = T::default().to_components().as_ref().len();
+		write!(f, "a sequence of {len} components")
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+		let mut components = T::default().to_components();
+		for (i, component) in components.as_mut().iter_mut().enumerate() {
+			*component = seq
+				.next_element()?
+				.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+		}
+		Ok(T::from_components(components))
+	}
+}
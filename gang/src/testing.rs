@@ -0,0 +1,155 @@
+//! Checks of the algebra laws, to use with `proptest`.
+//!
+//! The `check_*` functions return a [`TestCaseError`] on failure, so they can be called with `?`
+//! inside `proptest!`, including on values built by downstream code. [`test_algebra_laws!`]
+//! generates the tests of every law for a whole `gN` module.
+//!
+//! ```
+//! # #[cfg(feature = "g3")] {
+//! use gang::g3::*;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(r: Rot, v: V1)| {
+//!     gang::testing::check_rotate_preserves_norm(r, v, 1e-4)?;
+//! });
+//! # }
+//! ```
+//!
+//! [`test_algebra_laws!`]: crate::test_algebra_laws
+
+use core::{
+	fmt::Debug,
+	ops::{Mul, Neg},
+};
+
+/// The `proptest` crate, which the expansion of [`test_algebra_laws!`] refers to
+///
+/// [`test_algebra_laws!`]: crate::test_algebra_laws
+pub use proptest;
+use proptest::{prop_assert, test_runner::TestCaseError};
+
+use crate::{Rotate, Wedge};
+
+/// What the checks need to compare results, implemented by the types of every `gN` module
+pub trait Checkable: Copy + Debug {
+	fn norm(self) -> f32;
+
+	fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool;
+}
+
+/// `(a * b) * c == a * (b * c)`, up to `tolerance` relative to the magnitude of the operands
+pub fn check_mul_associative<T>(a: T, b: T, c: T, tolerance: f32) -> Result<(), TestCaseError>
+where
+	T: Checkable + Mul<Output = T>,
+{
+	let lhs = (a * b) * c;
+	let rhs = a * (b * c);
+	let scale = f32::max(1.0, a.norm() * b.norm() * c.norm());
+	prop_assert!(
+		lhs.abs_diff_eq(rhs, tolerance * scale),
+		"(a * b) * c = {:?} but a * (b * c) = {:?}",
+		lhs,
+		rhs
+	);
+	Ok(())
+}
+
+/// `a ∧ b == -(b ∧ a)`, up to `tolerance` relative to the magnitude of the operands
+pub fn check_wedge_antisymmetric<V>(a: V, b: V, tolerance: f32) -> Result<(), TestCaseError>
+where
+	V: Checkable + Wedge<V>,
+	V::Output: Checkable + Neg<Output = V::Output>,
+{
+	let lhs = a.wedge(b);
+	let rhs = -b.wedge(a);
+	let scale = f32::max(1.0, a.norm() * b.norm());
+	prop_assert!(
+		lhs.abs_diff_eq(rhs, tolerance * scale),
+		"a ∧ b = {:?} but -(b ∧ a) = {:?}",
+		lhs,
+		rhs
+	);
+	Ok(())
+}
+
+/// `|r.rotate(x)| == |x|` for a unit rotor `r`, up to `tolerance` relative to the magnitude of `x`
+pub fn check_rotate_preserves_norm<R, X>(r: R, x: X, tolerance: f32) -> Result<(), TestCaseError>
+where
+	R: Rotate<X, Output = X> + Debug,
+	X: Checkable,
+{
+	let norm = x.norm();
+	let rotated = r.rotate(x);
+	prop_assert!(
+		(rotated.norm() - norm).abs() <= tolerance * f32::max(1.0, norm),
+		"|{:?}| = {} but its rotation is {:?} with norm {}",
+		x,
+		norm,
+		rotated,
+		rotated.norm()
+	);
+	Ok(())
+}
+
+/// Generates a module of `proptest` tests checking every law on a `gN` module,
+/// e.g. `gang::test_algebra_laws!(gang::g3);`
+///
+/// Modules without some of the products can list the laws to check instead, among
+/// `rot_mul_associative`, `v1_wedge_antisymmetric`, `rotate_preserves_v1_norm` and
+/// `rotate_preserves_v2_norm`, e.g. `gang::test_algebra_laws!(gang::g1, [rot_mul_associative])`.
+#[macro_export]
+macro_rules! test_algebra_laws {
+	($module:path) => {
+		$crate::test_algebra_laws!(
+			$module,
+			[
+				rot_mul_associative,
+				v1_wedge_antisymmetric,
+				rotate_preserves_v1_norm,
+				rotate_preserves_v2_norm
+			]
+		);
+	};
+	($module:path, [$($law:ident),* $(,)?]) => {
+		#[cfg(test)]
+		mod algebra_laws {
+			use $module as m;
+
+			$(
+				$crate::test_algebra_laws!(@law $law);
+			)*
+		}
+	};
+	(@law rot_mul_associative) => {
+		$crate::testing::proptest::proptest! {
+			#[test]
+			fn rot_mul_associative(a: m::Rot, b: m::Rot, c: m::Rot) {
+				$crate::testing::check_mul_associative(a, b, c, 1e-4)?;
+			}
+		}
+	};
+	(@law v1_wedge_antisymmetric) => {
+		$crate::testing::proptest::proptest! {
+			#[test]
+			fn v1_wedge_antisymmetric(a: m::V1, b: m::V1) {
+				$crate::testing::check_wedge_antisymmetric(a, b, 1e-4)?;
+			}
+		}
+	};
+	(@law rotate_preserves_v1_norm) => {
+		$crate::testing::proptest::proptest! {
+			#[test]
+			fn rotate_preserves_v1_norm(r: m::Rot, x: m::V1) {
+				$crate::testing::check_rotate_preserves_norm(r, x, 1e-4)?;
+			}
+		}
+	};
+	(@law rotate_preserves_v2_norm) => {
+		$crate::testing::proptest::proptest! {
+			#[test]
+			fn rotate_preserves_v2_norm(r: m::Rot, x: m::V2) {
+				$crate::testing::check_rotate_preserves_norm(r, x, 1e-4)?;
+			}
+		}
+	};
+}
@@ -0,0 +1,77 @@
+//! The algebra laws of every enabled module.
+
+#![cfg(feature = "proptest")]
+
+#[cfg(feature = "g1")]
+mod g1 {
+	// there is no bivector in 1D
+	gang::test_algebra_laws!(gang::g1, [rot_mul_associative, rotate_preserves_v1_norm]);
+}
+
+#[cfg(feature = "g2")]
+mod g2 {
+	gang::test_algebra_laws!(gang::g2);
+}
+
+#[cfg(feature = "g3")]
+mod g3 {
+	gang::test_algebra_laws!(gang::g3);
+}
+
+#[cfg(feature = "g4")]
+mod g4 {
+	gang::test_algebra_laws!(gang::g4);
+}
+
+#[cfg(feature = "g5")]
+mod g5 {
+	gang::test_algebra_laws!(gang::g5);
+}
+
+#[cfg(feature = "g6")]
+mod g6 {
+	#[cfg(feature = "g6_rotate")]
+	gang::test_algebra_laws!(gang::g6);
+	#[cfg(not(feature = "g6_rotate"))]
+	gang::test_algebra_laws!(
+		gang::g6,
+		[
+			rot_mul_associative,
+			v1_wedge_antisymmetric,
+			rotate_preserves_v1_norm
+		]
+	);
+}
+
+#[cfg(feature = "g7")]
+mod g7 {
+	#[cfg(feature = "g7_rotate")]
+	gang::test_algebra_laws!(gang::g7);
+	#[cfg(not(feature = "g7_rotate"))]
+	gang::test_algebra_laws!(
+		gang::g7,
+		[
+			rot_mul_associative,
+			v1_wedge_antisymmetric,
+			rotate_preserves_v1_norm
+		]
+	);
+}
+
+/// `unit_v1` only produces unit vectors, and `Mv` components are in `COMPONENT`
+#[cfg(feature = "g4")]
+mod strategies {
+	use proptest::prelude::*;
+
+	proptest! {
+		#[test]
+		fn unit_v1(v in gang::g4::strategy::unit_v1()) {
+			prop_assert!((v.norm() - 1.0).abs() < 1e-5);
+		}
+
+		#[test]
+		fn mv_arbitrary(mv: gang::g4::Mv) {
+			prop_assert!(mv.to_array().iter().all(|x| gang::g4::strategy::COMPONENT.contains(x)));
+		}
+	}
+}
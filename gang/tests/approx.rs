@@ -0,0 +1,85 @@
+//! The `approx` traits compare every component, and rotors can also be compared up to sign.
+
+#![cfg(all(feature = "approx", feature = "g3", feature = "g4"))]
+
+mod common;
+
+use approx::{
+	assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq, assert_ulps_ne,
+	AbsDiffEq, RelativeEq, UlpsEq,
+};
+
+macro_rules! check_approx {
+	($module:ident) => {
+		mod $module {
+			use gang::$module::*;
+
+			use super::*;
+			use crate::common::components;
+
+			crate::common::rotors!();
+
+			#[test]
+			fn vectors() {
+				let v = V1::from_array(components(1));
+				// one ulp away
+				let close = V1::from_array(v.to_array().map(|c| f32::from_bits(c.to_bits() + 1)));
+				assert_abs_diff_eq!(v, close, epsilon = 1e-5);
+				assert_relative_eq!(v, close);
+				assert_ulps_eq!(v, close);
+				assert_relative_eq!(v * 1e6, close * 1e6, max_relative = 1e-6);
+
+				// just outside the tolerances
+				let mut far = v;
+				far.e0 += 1.1e-3;
+				assert!(!AbsDiffEq::abs_diff_eq(&v, &far, 1e-3));
+				assert!(!v.relative_eq(&far, 1e-3, 1e-4));
+				assert!(!v.ulps_eq(&far, 1e-3, 4));
+				assert_relative_ne!(v, far);
+				assert_ulps_ne!(v, far);
+				// only the largest difference counts
+				assert!(AbsDiffEq::abs_diff_eq(&v, &far, 1.2e-3));
+			}
+
+			#[test]
+			fn multivectors() {
+				let mv = Mv::from_array(components(2));
+				let close = Mv::from_array(mv.to_array().map(|c| c + 1e-7));
+				assert_abs_diff_eq!(mv, close, epsilon = 1e-5);
+				assert_relative_eq!(mv, close, epsilon = 1e-6);
+				assert_ulps_eq!(mv, close, epsilon = 1e-6);
+
+				let mut far = mv;
+				far.e = mv.e + 1e-3;
+				assert!(!AbsDiffEq::abs_diff_eq(&mv, &far, 9e-4));
+				assert!(AbsDiffEq::abs_diff_eq(&mv, &far, 1.1e-3));
+				assert!(!mv.relative_eq(&far, 9e-4, 1e-4));
+				assert!(!mv.ulps_eq(&far, 9e-4, 4));
+			}
+
+			#[test]
+			fn rotors_up_to_sign() {
+				for rot in rotors() {
+					assert_relative_eq!(rot, rot * rot.norm().recip(), max_relative = 1e-5);
+					assert_ulps_eq!(rot, rot * Rot::IDENTITY, epsilon = 1e-6);
+					assert_relative_ne!(rot, rot * -1.0);
+
+					// `R` and `-R` are the same rotation
+					let neg = rot * -1.0;
+					assert!(rot.relative_eq_up_to_sign(&neg, 1e-6, 1e-6));
+					assert!(rot.ulps_eq_up_to_sign(&neg, 1e-6, 4));
+					assert!(rot.abs_diff_eq_up_to_sign(neg, 1e-6));
+
+					// but not a slightly different rotation
+					let other = Rot::from_v2_angle(V2::E02, 2e-3) * rot;
+					assert!(!rot.relative_eq_up_to_sign(&(other * -1.0), 1e-4, 1e-4));
+					assert!(!rot.ulps_eq_up_to_sign(&(other * -1.0), 1e-4, 4));
+					assert!(rot.relative_eq_up_to_sign(&(other * -1.0), 2e-3, 0.0));
+				}
+			}
+		}
+	};
+}
+
+check_approx!(g3);
+check_approx!(g4);
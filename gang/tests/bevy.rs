@@ -0,0 +1,76 @@
+//! Propagation of the transforms through the entity hierarchy.
+
+#![cfg(all(feature = "bevy", feature = "g3"))]
+
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use gang::{
+	bevy::{
+		g3::{GlobalTransformN, TransformN},
+		TransformNPlugin,
+	},
+	g3::{Rot, V1, V2},
+};
+
+fn global(app: &App, entity: Entity) -> GlobalTransformN {
+	*app.world().get::<GlobalTransformN>(entity).unwrap()
+}
+
+#[test]
+fn parent_child_chain() {
+	let mut app = App::new();
+	app.add_plugins(TransformNPlugin);
+
+	let root_transform = TransformN::from_translation(V1::new(1.0, 0.0, 0.0))
+		.with_rotation(Rot::from_v2_angle(V2::E01, core::f32::consts::FRAC_PI_2))
+		.with_scale(2.0);
+	let child_transform = TransformN::from_translation(V1::new(0.0, 0.0, 3.0));
+	let leaf_transform = TransformN::from_translation(V1::new(1.0, 0.0, 0.0));
+
+	let root = app.world_mut().spawn(root_transform).id();
+	let child = app.world_mut().spawn((child_transform, ChildOf(root))).id();
+	let leaf = app.world_mut().spawn((leaf_transform, ChildOf(child))).id();
+	app.update();
+
+	assert_eq!(global(&app, root).to_transform(), root_transform);
+	let leaf_global = global(&app, leaf);
+	assert_eq!(
+		leaf_global,
+		GlobalTransformN::from(
+			root_transform.mul_transform(child_transform.mul_transform(leaf_transform))
+		)
+	);
+	// e0 is turned into e1, and scaled by the root
+	assert!(leaf_global
+		.translation()
+		.abs_diff_eq(V1::new(1.0, 2.0, 6.0), 1e-5));
+
+	// moving the root moves the whole chain
+	app.world_mut()
+		.get_mut::<TransformN>(root)
+		.unwrap()
+		.translation = V1::ZERO;
+	app.update();
+	assert!(global(&app, leaf)
+		.translation()
+		.abs_diff_eq(V1::new(0.0, 2.0, 6.0), 1e-5));
+
+	// changing a child only updates its subtree
+	app.world_mut().get_mut::<TransformN>(child).unwrap().scale = 0.5;
+	app.update();
+	assert!(global(&app, leaf)
+		.translation()
+		.abs_diff_eq(V1::new(0.0, 1.0, 6.0), 1e-5));
+
+	// a reparented entity follows its new parent
+	app.world_mut().entity_mut(leaf).insert(ChildOf(root));
+	app.update();
+	assert!(global(&app, leaf)
+		.translation()
+		.abs_diff_eq(V1::new(0.0, 2.0, 0.0), 1e-5));
+
+	// and an orphan is its own global transform
+	app.world_mut().entity_mut(leaf).remove::<ChildOf>();
+	app.update();
+	assert_eq!(global(&app, leaf).to_transform(), leaf_transform);
+}
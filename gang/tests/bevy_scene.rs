@@ -0,0 +1,84 @@
+//! Scenes holding gang types survive a serialization round trip.
+
+#![cfg(all(
+	feature = "bevy",
+	feature = "bevy_reflect",
+	feature = "serde",
+	feature = "g3"
+))]
+
+use bevy_ecs::{entity::EntityHashMap, prelude::*, reflect::AppTypeRegistry};
+use bevy_reflect::Reflect;
+use bevy_scene::{
+	serde::{SceneDeserializer, SceneSerializer},
+	DynamicScene, DynamicSceneBuilder,
+};
+use gang::{
+	bevy::g3::TransformN,
+	g3::{Mv, Rot, V1x4, V2},
+};
+use serde::de::DeserializeSeed;
+
+/// A component made of the types that aren't in `TransformN`
+#[derive(Component, Reflect, Clone, PartialEq, Debug, Default)]
+#[reflect(Component)]
+struct Shape {
+	planes: V1x4,
+	extent: Mv,
+}
+
+fn registry() -> AppTypeRegistry {
+	let registry = AppTypeRegistry::default();
+	{
+		let mut registry = registry.write();
+		registry.register::<TransformN>();
+		registry.register::<Shape>();
+		gang::g3::register_types(&mut registry);
+	}
+	registry
+}
+
+#[test]
+fn dynamic_scene_round_trip() {
+	let transform = TransformN::from_rotation(Rot::from_v2_angle(V2::E12, 0.7))
+		.with_translation(gang::g3::V1::new(1.0, -2.0, 3.5))
+		.with_scale(2.0);
+	let shape = Shape {
+		planes: V1x4::splat(gang::g3::V1::E2),
+		extent: Mv::ZERO + 1.5,
+	};
+
+	let mut world = World::new();
+	world.insert_resource(registry());
+	let entity = world.spawn((transform, shape.clone())).id();
+
+	let scene = DynamicSceneBuilder::from_world(&world)
+		.deny_component::<gang::bevy::g3::GlobalTransformN>()
+		.extract_entity(entity)
+		.build();
+
+	let registry = registry();
+	let json = {
+		let registry = registry.read();
+		serde_json::to_string(&SceneSerializer::new(&scene, &registry)).unwrap()
+	};
+	let scene: DynamicScene = {
+		let registry = registry.read();
+		SceneDeserializer {
+			type_registry: &registry,
+		}
+		.deserialize(&mut serde_json::Deserializer::from_str(&json))
+		.unwrap()
+	};
+
+	let mut loaded = World::new();
+	loaded.insert_resource(registry);
+	scene
+		.write_to_world(&mut loaded, &mut EntityHashMap::default())
+		.unwrap();
+
+	let mut query = loaded.query::<(&TransformN, &Shape)>();
+	let (loaded_transform, loaded_shape) = query.single(&loaded).unwrap();
+	assert_eq!(*loaded_transform, transform);
+	assert_eq!(*loaded_shape, shape);
+}
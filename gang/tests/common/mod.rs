@@ -0,0 +1,46 @@
+//! Fixtures shared by the integration tests.
+
+#![allow(dead_code, unused_macros, unused_imports)] // each test file only uses some of them
+
+/// Component `j` of the value number `seed`, distinct enough between components and seeds
+pub fn component(seed: usize, j: usize) -> f32 {
+	((seed * 7 + j * 3) % 11) as f32 / 2.0 - 2.5
+}
+
+/// The components of the value number `seed`, for `from_array`
+pub fn components<const N: usize>(seed: usize) -> [f32; N] {
+	core::array::from_fn(|j| component(seed, j))
+}
+
+/// `fn rotors() -> Vec<Rot>` for the `gN` module in scope, with at least 8 rotors: the
+/// identity, rotations in a few planes, their product, and products of rotations in every
+/// basis plane, with angles all around the circle
+macro_rules! rotors {
+	() => {
+		fn rotors() -> Vec<Rot> {
+			let mut rotors = vec![Rot::IDENTITY];
+			for i in 0..4 {
+				// simple planes, so that the rotors are rotations from 4 dimensions too
+				let (a, b) = (
+					crate::common::components(i),
+					crate::common::components(i + 3),
+				);
+				let plane = V1::from_array(a).wedge(V1::from_array(b)).normalize();
+				rotors.push(Rot::from_v2_angle(plane, 0.4 * i as f32 - 1.0));
+			}
+			rotors.push(rotors[1] * rotors[2]);
+			for i in 0..8 {
+				let planes = V2::ZERO.to_array().len();
+				rotors.push((0..planes).fold(Rot::IDENTITY, |rot, k| {
+					let mut plane = V2::ZERO.to_array();
+					plane[k] = 1.0;
+					let angle = 0.8 * i as f32 - 3.0 + k as f32;
+					Rot::from_v2_angle(V2::from_array(plane), angle) * rot
+				}));
+			}
+			rotors
+		}
+	};
+}
+
+pub(crate) use rotors;
@@ -0,0 +1,60 @@
+//! The `deterministic` feature gives the same bits on every target.
+//!
+//! The expected bits are those of the software implementations, so a change of any of them
+//! breaks the results shared by the peers of a lockstep simulation.
+
+#![cfg(all(feature = "deterministic", feature = "g2", feature = "g3"))]
+
+use gang::math;
+
+fn bits<const N: usize>(values: [f32; N]) -> [u32; N] {
+	values.map(f32::to_bits)
+}
+
+#[test]
+fn math_functions() {
+	let (s, c) = math::sin_cos(0.7);
+	let out = bits([math::sqrt(2.0), s, c, math::atan2(0.3, -0.8)]);
+	assert_eq!(out, [0x3fb504f3, 0x3f24eb73, 0x3f43ccb3, 0x403219c1]);
+}
+
+#[test]
+fn rotors() {
+	use gang::g3::*;
+
+	let plane = V2::new(0.3, -1.2, 0.5).normalize();
+	let rot = Rot::from_v2_angle(plane, 1.234);
+	let rotated = rot.rotate(V1::new(1.0, 2.0, 3.0));
+	let out = bits([
+		rot.e,
+		rot.e01,
+		rot.e02,
+		rot.e12,
+		rotated.e0,
+		rotated.e1,
+		rotated.e2,
+		rotated.norm(),
+		(rot * rot).normalize().e12,
+	]);
+	assert_eq!(
+		out,
+		[
+			0x3f50cc56, 0xbe053960, 0x3f053960, 0xbe5e0a4a, 0x404ab6b8, 0x3fc37c82, 0x3fa3ace6,
+			0x406f7751, 0xbeb51997,
+		]
+	);
+}
+
+#[test]
+fn angles() {
+	use gang::g2::*;
+
+	let rot = Rot::from_angle(2.5);
+	let out = bits([
+		rot.angle(),
+		rot.e,
+		rot.e01,
+		V1::new(3.0, -4.5).angle_between(V1::E1),
+	]);
+	assert_eq!(out, [0x40200000, 0x3ea171ef, 0xbf72f0a8, 0x40236e05]);
+}
@@ -0,0 +1,97 @@
+//! Statistical smoke tests of the random distributions, on a seeded generator.
+
+#![cfg(all(feature = "rand", feature = "g3", feature = "g4"))]
+
+use gang::distr::{UniformRotor, UnitSimpleBivector, UnitSphere};
+use rand::{distr::StandardUniform, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const SAMPLES: usize = 4000;
+/// Several standard deviations of the mean of a unit vector component, at most 1/sqrt(3 SAMPLES)
+const MEAN_TOLERANCE: f32 = 0.06;
+
+fn rng() -> ChaCha8Rng {
+	ChaCha8Rng::seed_from_u64(0x6a6e67)
+}
+
+macro_rules! check_distributions {
+	($module:ident) => {
+		mod $module {
+			use gang::$module::*;
+
+			use super::*;
+
+			#[test]
+			fn uniform_rotor() {
+				let mut rng = rng();
+				let axes = V1::ZERO.to_array().len();
+				let mut means = vec![vec![0.0; axes]; axes];
+				for _ in 0..SAMPLES {
+					let rot: Rot = rng.sample(UniformRotor);
+					assert!((rot.norm() - 1.0).abs() < 1e-4, "{rot:?}");
+					for (axis, mean) in means.iter_mut().enumerate() {
+						let mut e = V1::ZERO.to_array();
+						e[axis] = 1.0;
+						let rotated = rot.rotate(V1::from_array(e)).to_array();
+						for (m, x) in mean.iter_mut().zip(rotated) {
+							*m += x / SAMPLES as f32;
+						}
+					}
+				}
+				for mean in means.iter().flatten() {
+					assert!(mean.abs() < MEAN_TOLERANCE, "{means:?}");
+				}
+			}
+
+			#[test]
+			fn unit_sphere() {
+				let mut rng = rng();
+				let mut mean = V1::ZERO;
+				for _ in 0..SAMPLES {
+					let v: V1 = rng.sample(UnitSphere);
+					assert!((v.norm() - 1.0).abs() < 1e-5, "{v:?}");
+					mean += v * (1.0 / SAMPLES as f32);
+				}
+				assert!(mean.norm() < MEAN_TOLERANCE, "{mean:?}");
+			}
+
+			#[test]
+			fn unit_simple_bivector() {
+				let mut rng = rng();
+				for _ in 0..SAMPLES {
+					let b: V2 = rng.sample(UnitSimpleBivector);
+					assert!((b.norm() - 1.0).abs() < 1e-5, "{b:?}");
+				}
+			}
+
+			#[test]
+			fn standard_uniform() {
+				let mut rng = rng();
+				for _ in 0..SAMPLES {
+					let mv: Mv = rng.sample(StandardUniform);
+					assert!(
+						mv.to_array().iter().all(|x| (0.0..1.0).contains(x)),
+						"{mv:?}"
+					);
+					let rot: Rot = rng.sample(StandardUniform);
+					assert!((rot.norm() - 1.0).abs() < 1e-4, "{rot:?}");
+				}
+			}
+		}
+	};
+}
+
+check_distributions!(g3);
+check_distributions!(g4);
+
+/// A bivector is simple when its wedge with itself vanishes, which always holds below 4D
+#[test]
+fn simple_bivectors_in_4d() {
+	use gang::g4::*;
+
+	let mut rng = rng();
+	for _ in 0..SAMPLES {
+		let b: V2 = rng.sample(UnitSimpleBivector);
+		assert!(b.wedge(b).abs_diff_eq(V4::ZERO, 1e-5), "{b:?}");
+	}
+}
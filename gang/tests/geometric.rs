@@ -0,0 +1,235 @@
+//! `*` is the geometric product of `gang::dynamic::Multivector` between every pair of types. The
+//! component-wise operations act on each component alone.
+
+#![cfg(all(feature = "alloc", any(feature = "g3", feature = "g4", feature = "g6")))]
+
+mod common;
+
+use gang::dynamic::{Multivector, Signature};
+
+/// Blades in the order of the generated components: by grade, then lexicographically
+fn blade_order(dim: u32) -> Vec<u32> {
+	let mut blades = (0..1 << dim).collect::<Vec<u32>>();
+	blades.sort_by_key(|&blade| {
+		let indices = (0..dim).filter(|i| blade & 1 << i != 0).collect::<Vec<_>>();
+		(blade.count_ones(), indices)
+	});
+	blades
+}
+
+/// Conversion of a generated type to and from the blades of its grades
+trait Dynamic: Copy {
+	fn has_grade(grade: u32) -> bool;
+	fn components(self) -> Vec<f32>;
+	fn from_components(components: &[f32]) -> Self;
+
+	fn blades(dim: u32) -> Vec<u32> {
+		blade_order(dim)
+			.into_iter()
+			.filter(|blade| Self::has_grade(blade.count_ones()))
+			.collect()
+	}
+
+	fn to_dynamic(self, dim: u32) -> Multivector {
+		let mut out = Multivector::zero(Signature::euclidean(dim));
+		for (blade, c) in Self::blades(dim).into_iter().zip(self.components()) {
+			out[blade] = c;
+		}
+		out
+	}
+
+	/// A value with distinct components, from `seed`
+	fn sample(dim: u32, seed: usize) -> Self {
+		let len = Self::blades(dim).len();
+		let components = (0..len)
+			.map(|j| common::component(seed, j))
+			.collect::<Vec<_>>();
+		Self::from_components(&components)
+	}
+}
+
+fn assert_close(lhs: &Multivector, rhs: &Multivector) {
+	let diff = lhs - rhs;
+	assert!(diff.norm() < 1e-3, "{lhs} != {rhs}");
+}
+
+macro_rules! check_geometric {
+	($module:ident, $dim:literal, [$($kind:ident: $grade:literal,)*], [$($rot:ident),*], [$($lhs:ident * $rhs:ident,)*], [$($full_lhs:ident * $full_rhs:ident,)*]) => {
+		mod $module {
+			use gang::$module::*;
+
+			use super::{assert_close, Dynamic};
+
+			$(
+				impl Dynamic for $kind {
+					fn has_grade(grade: u32) -> bool {
+						grade == $grade
+					}
+
+					fn components(self) -> Vec<f32> {
+						self.to_array().to_vec()
+					}
+
+					fn from_components(components: &[f32]) -> Self {
+						Self::from_array(components.try_into().unwrap())
+					}
+				}
+			)*
+
+			impl Dynamic for Rot {
+				fn has_grade(grade: u32) -> bool {
+					grade % 2 == 0
+				}
+
+				fn components(self) -> Vec<f32> {
+					vec![$(self.$rot),*]
+				}
+
+				fn from_components(components: &[f32]) -> Self {
+					let mut components = components.iter().copied();
+					Rot {
+						$(
+							$rot: components.next().unwrap(),
+						)*
+					}
+				}
+			}
+
+			impl Dynamic for Mv {
+				fn has_grade(_: u32) -> bool {
+					true
+				}
+
+				fn components(self) -> Vec<f32> {
+					self.to_array().to_vec()
+				}
+
+				fn from_components(components: &[f32]) -> Self {
+					Self::from_array(components.try_into().unwrap())
+				}
+			}
+
+			#[test]
+			fn mul_matches_dynamic() {
+				$(
+					for seed in 0..4 {
+						let (lhs, rhs) = ($lhs::sample($dim, seed), $rhs::sample($dim, seed + 5));
+						let expected = lhs.to_dynamic($dim) * rhs.to_dynamic($dim);
+						assert_close(&(lhs * rhs).to_dynamic($dim), &expected);
+					}
+				)*
+			}
+
+			#[test]
+			fn full_mul_matches_dynamic() {
+				$(
+					for seed in 0..4 {
+						let (lhs, rhs) = ($full_lhs::sample($dim, seed), $full_rhs::sample($dim, seed + 5));
+						let expected = lhs.to_dynamic($dim) * rhs.to_dynamic($dim);
+						assert_close(&(lhs * rhs).to_dynamic($dim), &expected);
+					}
+				)*
+			}
+
+			#[test]
+			fn elementwise() {
+				let a = V1::from_array(core::array::from_fn(|i| i as f32 + 1.0));
+				let b = V1::from_array(core::array::from_fn(|i| 2.0 - i as f32 * 3.0));
+				let product = a.mul_elementwise(b).to_array();
+				let quotient = a.div_elementwise(b).to_array();
+				let recip = b.recip().to_array();
+				for i in 0..$dim {
+					assert_eq!(product[i], a.to_array()[i] * b.to_array()[i]);
+					assert_eq!(quotient[i], a.to_array()[i] / b.to_array()[i]);
+					assert_eq!(recip[i], 1.0 / b.to_array()[i]);
+				}
+				assert_eq!(a.mul_elementwise(b), b.mul_elementwise(a));
+				assert!(a.mul_elementwise(b).div_elementwise(b).abs_diff_eq(a, 1e-6));
+				assert!(b.mul_elementwise(b.recip()).abs_diff_eq(V1::from_array([1.0; $dim]), 1e-6));
+
+				// unlike `*`, which is the geometric product
+				assert_eq!(V1::E0.mul_elementwise(V1::E1), V1::ZERO);
+				assert_eq!((V1::E0 * V1::E1).e, 0.0);
+				assert_eq!((V1::E0 * V1::E1).e01, 1.0);
+
+				let mv = Mv::from_array(core::array::from_fn(|i| i as f32 - 0.5));
+				assert!(mv.mul_elementwise(mv.recip()).abs_diff_eq(Mv::from_array([1.0; 1 << $dim]), 1e-5));
+				let zero = V2::ZERO.recip().to_array();
+				assert!(zero.iter().all(|c| c.is_infinite()));
+			}
+		}
+	};
+}
+
+#[cfg(feature = "g3")]
+check_geometric!(
+	g3,
+	3,
+	[V0: 0, V1: 1, V2: 2, V3: 3,],
+	[e, e01, e02, e12],
+	[
+		V1 * V1, V1 * V2, V2 * V1, V2 * V2, Rot * V1, V1 * Rot, Rot * V2, V2 * Rot, Rot * Rot,
+	],
+	[
+		V1 * V3, V3 * V1, V2 * V3, V3 * V3, V0 * V2, Rot * Mv, Mv * V2, V3 * Mv, Mv * Mv,
+	]
+);
+#[cfg(feature = "g4")]
+check_geometric!(
+	g4,
+	4,
+	[V0: 0, V1: 1, V2: 2, V3: 3, V4: 4,],
+	[e, e01, e02, e03, e12, e13, e23, e0123],
+	[
+		V1 * V1, V1 * V2, V2 * V1, V2 * V2, Rot * V1, V1 * Rot, Rot * V2, V2 * Rot, Rot * Rot,
+	],
+	[
+		V1 * V3, V3 * V1, V2 * V3, V3 * V2, V1 * V4, V4 * V4, V2 * V4, Rot * V3, V4 * Rot,
+		Rot * Mv, Mv * V1, Mv * Mv,
+	]
+);
+
+/// From 6 dimensions, the core products include `Mv * Mv`, `V1 * Rot` and `Rot * V1`
+#[cfg(feature = "g6")]
+mod g6 {
+	use gang::{dynamic::Signature, g6::*};
+
+	use super::{assert_close, blade_order, common::components};
+
+	fn to_dynamic(mv: Mv) -> gang::dynamic::Multivector {
+		let mut out = gang::dynamic::Multivector::zero(Signature::euclidean(6));
+		for (blade, c) in blade_order(6).into_iter().zip(mv.to_array()) {
+			out[blade] = c;
+		}
+		out
+	}
+
+	#[test]
+	fn mv_mul_matches_dynamic() {
+		for seed in 0..4 {
+			let (lhs, rhs) = (
+				Mv::from_array(components(seed)),
+				Mv::from_array(components(seed + 5)),
+			);
+			assert_close(&to_dynamic(lhs * rhs), &(to_dynamic(lhs) * to_dynamic(rhs)));
+		}
+	}
+
+	#[test]
+	fn v1_mul_rot() {
+		let (s, c) = (0.25f32).sin_cos();
+		let rot = Rot::from_v2_angle(V2::E01, 0.5);
+		let lhs = V1::E0 * rot;
+		let rhs = rot * V1::E0;
+		assert!(
+			(lhs.e0 - c).abs() < 1e-6 && (lhs.e1 + s).abs() < 1e-6,
+			"{lhs:?}"
+		);
+		assert!(
+			(rhs.e0 - c).abs() < 1e-6 && (rhs.e1 - s).abs() < 1e-6,
+			"{rhs:?}"
+		);
+		assert_eq!((V1::E4 * Rot::IDENTITY).e4, 1.0);
+		assert_eq!((Rot::IDENTITY * V1::E4).e4, 1.0);
+	}
+}
@@ -0,0 +1,98 @@
+//! Round trips through the `glam` types, and rotations agreeing with glam's.
+
+#![cfg(all(feature = "glam", feature = "g2", feature = "g3", feature = "g4"))]
+
+macro_rules! check_vector {
+	($module:ident, $vector:ident) => {
+		mod $module {
+			use gang::$module::*;
+
+			#[test]
+			fn vector() {
+				let v = V1::from_array(core::array::from_fn(|i| i as f32 * 1.5 - 2.0));
+				let converted = glam::$vector::from(v);
+				assert_eq!(V1::from(converted), v);
+				assert_eq!(converted.to_array(), v.to_array());
+				assert_eq!(V1::from(glam::$vector::X), V1::E0);
+				assert_eq!(V1::from(glam::$vector::Y), V1::E1);
+			}
+		}
+	};
+}
+
+check_vector!(g2, Vec2);
+check_vector!(g3, Vec3);
+check_vector!(g4, Vec4);
+
+fn angles() -> impl Iterator<Item = f32> {
+	(0..12).map(|i| 0.55 * i as f32 - 3.0)
+}
+
+#[test]
+fn mat2() {
+	use gang::g2::*;
+
+	let v = V1::new(1.5, -0.5);
+	for angle in angles() {
+		let rot = Rot::from_angle(angle);
+		let m = glam::Mat2::from(rot);
+		// counterclockwise, from x to y, like `Mat2::from_angle`
+		assert!(
+			m.abs_diff_eq(glam::Mat2::from_angle(angle), 1e-6),
+			"{angle}"
+		);
+		assert!(rot
+			.rotate(v)
+			.abs_diff_eq(V1::from(m * glam::Vec2::from(v)), 1e-5));
+		assert!(Rot::from(m).abs_diff_eq_up_to_sign(rot, 1e-5), "{angle}");
+		let back = Rot::from(glam::Mat2::from_angle(angle));
+		assert!((glam::Mat2::from(back) - m).abs_diff_eq(glam::Mat2::ZERO, 1e-5));
+	}
+	assert!(Rot::from_angle(core::f32::consts::FRAC_PI_2)
+		.rotate(V1::E0)
+		.abs_diff_eq(V1::E1, 1e-6));
+}
+
+#[test]
+fn quat_axes() {
+	use gang::g3::*;
+
+	// each axis is dual to the plane it rotates, oriented so that both turn the same way
+	let axes = [
+		(glam::Vec3::X, V2::E12),
+		(glam::Vec3::Y, -V2::E02),
+		(glam::Vec3::Z, V2::E01),
+	];
+	for (axis, plane) in axes {
+		for angle in angles() {
+			let rot = Rot::from_v2_angle(plane, angle);
+			let quat = glam::Quat::from_axis_angle(axis, angle);
+			assert!(Rot::from(quat).abs_diff_eq(rot, 1e-6), "{axis} {angle}");
+			assert!(
+				glam::Quat::from(rot).abs_diff_eq(quat, 1e-6),
+				"{axis} {angle}"
+			);
+		}
+	}
+}
+
+#[test]
+fn quat_rotations() {
+	use gang::g3::*;
+
+	let vectors = [V1::E0, V1::new(1.0, 2.0, 3.0), V1::new(-0.5, 0.0, 4.0)];
+	for angle in angles() {
+		let axis = glam::Vec3::new(angle.sin(), 1.0, angle.cos() * 2.0).normalize();
+		let quat = glam::Quat::from_axis_angle(axis, angle);
+		let rot = Rot::from(quat);
+		assert_eq!(glam::Quat::from(rot), quat);
+		for v in vectors {
+			let expected = V1::from(quat * glam::Vec3::from(v));
+			assert!(rot.rotate(v).abs_diff_eq(expected, 1e-5), "{axis} {angle}");
+		}
+		// composition order is the same as for quaternions
+		let other = glam::Quat::from_axis_angle(glam::Vec3::X, 0.7);
+		let composed = Rot::from(quat) * Rot::from(other);
+		assert!(glam::Quat::from(composed).abs_diff_eq(quat * other, 1e-5));
+	}
+}
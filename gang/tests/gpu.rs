@@ -0,0 +1,63 @@
+//! Layouts of the GPU buffer types.
+
+#![cfg(all(feature = "g2", feature = "g3", feature = "g4"))]
+
+use core::mem::{align_of, size_of};
+
+macro_rules! check_layouts {
+	($module:ident, $($name:ident: $std140:ident, $std430:ident = $size140:literal, $size430:literal;)*) => {
+		#[test]
+		fn $module() {
+			use gang::$module::*;
+
+			$(
+				assert_eq!(size_of::<$std140>(), $size140, stringify!($std140));
+				assert_eq!(align_of::<$std140>(), 16, stringify!($std140));
+				assert_eq!(size_of::<$std430>(), $size430, stringify!($std430));
+				assert_eq!(align_of::<$std430>(), 4, stringify!($std430));
+
+				let v = $name::from_array(core::array::from_fn(|i| i as f32 + 1.0));
+				let padded = $std140::from(v);
+				assert_eq!($name::from(padded), v);
+				assert_eq!($std140::default(), $std140::from($name::default()));
+			)*
+		}
+	};
+}
+
+check_layouts! {
+	g2,
+	V1: V1Std140, V1Std430 = 16, 8;
+	V2: V2Std140, V2Std430 = 16, 4;
+	Mv: MvStd140, MvStd430 = 16, 16;
+}
+
+check_layouts! {
+	g3,
+	V0: V0Std140, V0Std430 = 16, 4;
+	V1: V1Std140, V1Std430 = 16, 12;
+	V2: V2Std140, V2Std430 = 16, 12;
+	V3: V3Std140, V3Std430 = 16, 4;
+	Mv: MvStd140, MvStd430 = 32, 32;
+}
+
+check_layouts! {
+	g4,
+	V1: V1Std140, V1Std430 = 16, 16;
+	V2: V2Std140, V2Std430 = 32, 24;
+	V3: V3Std140, V3Std430 = 16, 16;
+	Mv: MvStd140, MvStd430 = 64, 64;
+}
+
+/// `Rot` has no `ZERO`, so its round trip is checked on its own
+#[test]
+fn rotors() {
+	use gang::g3::{Rot, RotStd140, RotStd430, V2};
+
+	assert_eq!((size_of::<RotStd140>(), align_of::<RotStd140>()), (16, 16));
+	assert_eq!(size_of::<RotStd430>(), 16);
+	assert_eq!(size_of::<gang::g4::RotStd140>(), 32);
+
+	let rot = Rot::from_v2_angle(V2::E02, 0.5);
+	assert_eq!(Rot::from(RotStd140::from(rot)), rot);
+}
@@ -0,0 +1,21 @@
+//! The highest dimension accepted by `gang!` compiles with its default products.
+//!
+//! Expanding them takes minutes, so this only runs along with the other high dimensions.
+
+#![cfg(feature = "g7")]
+
+mod g8 {
+	gang::gang!(8);
+}
+
+#[test]
+fn core_products() {
+	use g8::*;
+	use gang::{Rotate, Wedge};
+
+	let rot = Rot::from_v2_angle(V2::E67, 0.5) * Rot::from_v2_angle(V2::E01, 1.0);
+	let rotated = rot.rotate(V1::E7);
+	assert!((rotated.norm() - 1.0).abs() < 1e-5);
+	assert!(rotated.abs_diff_eq(Rot::from_v2_angle(V2::E67, 0.5).rotate(V1::E7), 1e-5));
+	assert_eq!(V1::E0.wedge(V1::E7), V2::E07);
+}
@@ -0,0 +1,59 @@
+//! Round trips through the `mint` types.
+
+#![cfg(all(feature = "mint", feature = "g2", feature = "g3", feature = "g4"))]
+
+mod common;
+
+macro_rules! check_mint {
+	($module:ident, $vector:ident, $matrix:ident) => {
+		mod $module {
+			use gang::$module::*;
+
+			crate::common::rotors!();
+
+			#[test]
+			fn vector() {
+				let v = V1::from_array(core::array::from_fn(|i| i as f32 * 1.5 - 2.0));
+				assert_eq!(V1::from(mint::$vector::<f32>::from(v)), v);
+				assert_eq!(V1::from(mint::$vector::<f64>::from(v)), v);
+				assert_eq!(
+					<[f32; _]>::from(mint::$vector::<f32>::from(v)),
+					v.to_array()
+				);
+			}
+
+			#[test]
+			fn matrix() {
+				for rot in rotors() {
+					let m = mint::$matrix::<f32>::from(rot);
+					assert!(Rot::from(m).abs_diff_eq_up_to_sign(rot, 1e-4), "{rot:?}");
+					// the columns are the images of the basis vectors
+					let images = <[f32; _]>::from(m);
+					let e0 = rot.rotate(V1::E0).to_array();
+					assert_eq!(images[..e0.len()], e0);
+				}
+			}
+		}
+	};
+}
+
+check_mint!(g2, Vector2, ColumnMatrix2);
+check_mint!(g3, Vector3, ColumnMatrix3);
+check_mint!(g4, Vector4, ColumnMatrix4);
+
+#[test]
+fn quaternion() {
+	use gang::g3::*;
+
+	let rot = Rot::from_v2_angle(V2::new(0.3, -1.2, 0.5).normalize(), 1.234);
+	let q = mint::Quaternion::from(rot);
+	assert_eq!(Rot::from(q), rot);
+	assert!(Rot::from(mint::ColumnMatrix3::from(rot)).abs_diff_eq_up_to_sign(Rot::from(q), 1e-5));
+
+	// a quarter turn from x to y is a quarter turn around z
+	let rot = Rot::from_v2_angle(V2::E01, core::f32::consts::FRAC_PI_2);
+	let q = mint::Quaternion::from(rot);
+	let half = core::f32::consts::FRAC_1_SQRT_2;
+	assert!((q.s - half).abs() < 1e-6 && (q.v.z - half).abs() < 1e-6);
+	assert!(rot.rotate(V1::E0).abs_diff_eq(V1::E1, 1e-6));
+}
@@ -0,0 +1,146 @@
+//! Conversions to `nalgebra` rotate vectors like `Rotation<f32, N>`, and round trip.
+
+#![cfg(all(
+	feature = "nalgebra",
+	feature = "g2",
+	feature = "g3",
+	feature = "g4",
+	feature = "g5"
+))]
+
+mod common;
+
+macro_rules! check_nalgebra {
+	($module:ident, $dim:literal) => {
+		mod $module {
+			use gang::$module::*;
+			use nalgebra::{Rotation, SMatrix, SVector};
+
+			/// Rotations built by nalgebra alone, as products of rotations in each basis plane
+			fn rotations() -> Vec<Rotation<f32, $dim>> {
+				(0..6)
+					.map(|i| {
+						let mut m = SMatrix::<f32, $dim, $dim>::identity();
+						for a in 0..$dim {
+							for b in a + 1..$dim {
+								let angle = 0.7 * i as f32 - 2.0 + (a * $dim + b) as f32 * 0.3;
+								let mut givens = SMatrix::<f32, $dim, $dim>::identity();
+								let (s, c) = angle.sin_cos();
+								givens[(a, a)] = c;
+								givens[(b, b)] = c;
+								givens[(b, a)] = s;
+								givens[(a, b)] = -s;
+								m = givens * m;
+							}
+						}
+						Rotation::from_matrix_unchecked(m)
+					})
+					.collect()
+			}
+
+			fn vectors() -> Vec<V1> {
+				(0..3)
+					.map(|i| V1::from_array(crate::common::components(i)))
+					.collect()
+			}
+
+			#[test]
+			fn vector() {
+				for v in vectors() {
+					let converted = SVector::<f32, $dim>::from(v);
+					assert_eq!(converted.as_slice(), v.to_array());
+					assert_eq!(V1::from(converted), v);
+				}
+				let v2 = V2::from_array(core::array::from_fn(|i| i as f32));
+				assert_eq!(V2::from(SVector::from(v2)), v2);
+			}
+
+			#[test]
+			fn from_rotation() {
+				for rotation in rotations() {
+					let rot = Rot::from(rotation);
+					for v in vectors() {
+						let expected = V1::from(rotation * SVector::from(v));
+						assert!(rot.rotate(v).abs_diff_eq(expected, 1e-4), "{rotation}");
+					}
+				}
+			}
+
+			#[test]
+			fn round_trip() {
+				for rotation in rotations() {
+					let rot = Rot::from(rotation);
+					// `from_matrix_unchecked` keeps the matrix of the rotor as is
+					let back = Rotation::<f32, $dim>::from(rot);
+					assert!(
+						(back.matrix() - rotation.matrix()).norm() < 1e-4,
+						"{rotation}"
+					);
+					assert_eq!(back.matrix().as_slice(), rot.v1_rotation_matrix());
+					assert!(Rot::from(back).abs_diff_eq_up_to_sign(rot, 1e-4));
+					assert!(Rot::from_v1_rotation_matrix(rot.v1_rotation_matrix())
+						.abs_diff_eq_up_to_sign(rot, 1e-4));
+				}
+			}
+		}
+	};
+}
+
+check_nalgebra!(g2, 2);
+check_nalgebra!(g3, 3);
+check_nalgebra!(g4, 4);
+check_nalgebra!(g5, 5);
+
+#[test]
+fn rotation2_angle() {
+	use gang::g2::*;
+
+	for i in 0..8 {
+		let angle = 0.8 * i as f32 - 3.0;
+		let rotation = nalgebra::Rotation2::new(angle);
+		assert!(Rot::from(rotation).abs_diff_eq_up_to_sign(Rot::from_angle(angle), 1e-5));
+		assert!((nalgebra::Rotation2::from(Rot::from_angle(angle)).angle() - angle).abs() < 1e-5);
+	}
+}
+
+#[test]
+fn unit_quaternion() {
+	use gang::g3::*;
+	use nalgebra::{UnitQuaternion, Vector3};
+
+	// the rotor maps to `w = e, i = -e12, j = e02, k = -e01`: each axis is dual to the plane it
+	// rotates, oriented so that both turn the same way
+	let axes = [
+		(Vector3::x_axis(), V2::E12),
+		(Vector3::y_axis(), -V2::E02),
+		(Vector3::z_axis(), V2::E01),
+	];
+	for (axis, plane) in axes {
+		for i in 0..8 {
+			let angle = 0.8 * i as f32 - 3.0;
+			let q = UnitQuaternion::from_axis_angle(&axis, angle);
+			let rot = Rot::from_v2_angle(plane, angle);
+			assert!(Rot::from(q).abs_diff_eq(rot, 1e-6), "{axis:?} {angle}");
+			let back = UnitQuaternion::from(rot);
+			assert!((back.coords - q.coords).norm() < 1e-6, "{axis:?} {angle}");
+		}
+	}
+
+	let q = UnitQuaternion::from_axis_angle(
+		&nalgebra::Unit::new_normalize(Vector3::new(1.0, -2.0, 0.5)),
+		1.3,
+	);
+	let rot = Rot::from(q);
+	let v = V1::new(0.5, 1.0, -2.0);
+	assert!(rot
+		.rotate(v)
+		.abs_diff_eq(V1::from(q * Vector3::from(v)), 1e-5));
+	let q2 = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), 0.4);
+	let composed = UnitQuaternion::from(rot * Rot::from(q2));
+	assert!((composed.coords - (q * q2).coords).norm() < 1e-5);
+
+	// a rotor that drifted from unit length still gives a unit quaternion
+	let drifted = UnitQuaternion::from(rot * 1.1);
+	assert!((drifted.coords.norm() - 1.0).abs() < 1e-6);
+	assert!((drifted.coords - q.coords).norm() < 1e-5);
+}
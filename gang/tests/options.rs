@@ -0,0 +1,131 @@
+//! Restricted `gang!` invocations compile with every integration enabled, and only generate the
+//! products they ask for.
+
+#![cfg(all(
+	feature = "mint",
+	feature = "glam",
+	feature = "nalgebra",
+	feature = "serde",
+	feature = "bytemuck",
+	feature = "approx",
+	feature = "rand",
+	feature = "proptest",
+	feature = "bevy_reflect"
+))]
+
+use gang::{Rotate, Wedge};
+use rand::{distr::StandardUniform, Rng, SeedableRng};
+
+fn rng() -> rand_chacha::ChaCha8Rng {
+	rand_chacha::ChaCha8Rng::seed_from_u64(7)
+}
+
+/// Every product, on the minimal grades
+mod full {
+	gang::gang!(3, products = [geometric, rotate, wedge], grades = [1, 2]);
+}
+
+/// `Rotate` without the rotor product, so without `from_v1_rotation_matrix`, `UniformRotor`,
+/// `gang::Rotor`, `Rot` strategies nor `Algebra`
+mod rotate_only {
+	gang::gang!(5, products = [rotate], grades = [1, 2, 3]);
+}
+
+/// The rotor product without `Rotate` nor `Wedge`, so with `gang::Rotor` but no `Algebra`
+mod geometric_only {
+	gang::gang!(4, products = [geometric], grades = [1, 2]);
+}
+
+mod wedge_only {
+	gang::gang!(4, products = [wedge]);
+}
+
+/// Only the types
+mod types_only {
+	gang::gang!(6, products = [], grades = [1, 2]);
+}
+
+/// The generated imports don't collide with those of the invoking module
+mod own_imports {
+	#[allow(unused_imports)]
+	use bevy_reflect::{self, std_traits::ReflectDefault, ReflectSerialize};
+
+	gang::gang!(2, products = [wedge]);
+}
+
+#[test]
+fn full() {
+	use full::*;
+
+	let rot: Rot = rng().sample(gang::distr::UniformRotor);
+	let matrix = mint::ColumnMatrix3::from(rot);
+	assert!(Rot::from(matrix).abs_diff_eq_up_to_sign(rot, 1e-4));
+	assert!(rot.rotate(V1::E0).abs_diff_eq(Rot::from(glam::Quat::from(rot)).rotate(V1::E0), 1e-4));
+	assert_eq!(V1::E0.wedge(V1::E1), V2::E01);
+	assert_eq!(<full::Algebra as gang::Algebra>::DIM, 3);
+	let rotation = nalgebra::Rotation3::from_axis_angle(&nalgebra::Vector3::z_axis(), 0.5);
+	assert!(Rot::from(rotation).abs_diff_eq_up_to_sign(Rot::from_v2_angle(V2::E01, 0.5), 1e-5));
+
+	let json = serde_json::to_string(&rot).unwrap();
+	assert_eq!(serde_json::from_str::<Rot>(&json).unwrap(), rot);
+	assert_eq!(bytemuck::cast::<Rot, [f32; 4]>(rot)[0], rot.e);
+
+	let mut registry = bevy_reflect::TypeRegistry::default();
+	register_types(&mut registry);
+	assert!(registry.contains(core::any::TypeId::of::<Rotx8>()));
+	own_imports::register_types(&mut registry);
+	assert!(registry.contains(core::any::TypeId::of::<own_imports::Rot>()));
+}
+
+#[test]
+fn rotate_only() {
+	use rotate_only::*;
+
+	let v: V3 = rng().sample(StandardUniform);
+	let rot = Rot::from_v2_angle(V2::E13, 0.5);
+	assert!((rot.rotate(v).norm() - v.norm()).abs() < 1e-4);
+	let wide = V3x4::splat(v);
+	assert!(Rotx4::splat(rot).rotate(wide).lane(2).abs_diff_eq(rot.rotate(v), 1e-5));
+	assert_eq!(rot.v1_rotation_matrix().len(), 25);
+}
+
+#[test]
+fn geometric_only() {
+	use geometric_only::*;
+
+	let rot = Rot::from_v2_angle(V2::E03, 0.5);
+	assert!((rot * rot).abs_diff_eq(Rot::from_v2_angle(V2::E03, 1.0), 1e-5));
+	assert_eq!(<Rot as gang::Rotor>::IDENTITY, Rot::IDENTITY);
+	assert!((Rotx8::splat(rot) * Rotx8::splat(rot)).lane(3).abs_diff_eq(rot * rot, 1e-5));
+	let v: V2 = rng().sample(StandardUniform);
+	assert!(v.norm().is_finite());
+}
+
+#[test]
+fn wedge_only() {
+	use wedge_only::*;
+
+	assert_eq!(V2::E01.wedge(V2::E23), V4::E0123);
+	assert_eq!(nalgebra::SVector::<f32, 4>::from(V1::E3)[3], 1.0);
+}
+
+#[test]
+fn types_only() {
+	use types_only::*;
+
+	let v = V1::E5 + V1::E0;
+	assert_eq!(v.norm_squared(), 2.0);
+	let _: Mv = rng().sample(StandardUniform);
+}
+
+mod strategies {
+	use proptest::prelude::*;
+
+	proptest! {
+		#[test]
+		fn arbitrary(v: super::rotate_only::V3, r: super::full::Rot, mv: super::types_only::Mv) {
+			prop_assert!(v.norm().is_finite() && mv.norm().is_finite());
+			prop_assert!((r.norm() - 1.0).abs() < 1e-4);
+		}
+	}
+}
@@ -0,0 +1,44 @@
+//! Batch rotations give the same results as rotating each element.
+
+#![cfg(any(feature = "g3", feature = "g4"))]
+
+mod common;
+
+macro_rules! check_batch {
+	($module:ident, $($kind:ident),*) => {
+		mod $module {
+			use gang::$module::*;
+
+			use crate::common::components;
+
+			crate::common::rotors!();
+
+			$(
+				#[test]
+				#[allow(non_snake_case)]
+				fn $kind() {
+					let values = (0..16)
+						.map(|i| $kind::from_array(components(i)))
+						.collect::<Vec<_>>();
+					for r in rotors() {
+						let expected = values.iter().map(|&v| r.rotate(v)).collect::<Vec<_>>();
+
+						let mut slice = values.clone();
+						r.rotate_slice(&mut slice);
+						let iter = r.rotate_iter(values.iter().copied()).collect::<Vec<_>>();
+
+						for ((e, s), i) in expected.iter().zip(&slice).zip(&iter) {
+							assert!(e.abs_diff_eq(*s, 1e-4), "{e:?} != {s:?}");
+							assert!(e.abs_diff_eq(*i, 1e-4), "{e:?} != {i:?}");
+						}
+					}
+				}
+			)*
+		}
+	};
+}
+
+#[cfg(feature = "g3")]
+check_batch!(g3, V0, V1, V2, V3);
+#[cfg(feature = "g4")]
+check_batch!(g4, V0, V1, V2, V3, V4);
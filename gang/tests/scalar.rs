@@ -0,0 +1,57 @@
+//! Adding a scalar only changes the grade 0 part, and the output type holds the sum.
+
+#![cfg(feature = "g3")]
+
+use gang::g3::*;
+
+#[test]
+fn even_grades_give_rotors() {
+	let r: Rot = 1.0 + V2::E01;
+	assert_eq!((r.e, r.e01, r.e02, r.e12), (1.0, 1.0, 0.0, 0.0));
+	let r: Rot = V2::E12 - 2.0;
+	assert_eq!((r.e, r.e12), (-2.0, 1.0));
+	let r: Rot = 2.0 - V2::E12;
+	assert_eq!((r.e, r.e12), (2.0, -1.0));
+
+	let r: Rot = Rot::IDENTITY + 0.5;
+	assert_eq!(r.e, 1.5);
+	let mut r = Rot::IDENTITY;
+	r -= 3.0;
+	assert_eq!(r, Rot::IDENTITY * -2.0);
+}
+
+#[test]
+fn odd_grades_give_multivectors() {
+	let mv: Mv = V1::E0 + 2.0;
+	assert_eq!(mv, Mv { e: 2.0, e0: 1.0, ..Mv::ZERO });
+	let mv: Mv = 2.0 - V3::E012;
+	assert_eq!(mv, Mv { e: 2.0, e012: -1.0, ..Mv::ZERO });
+
+	let mut mv = mv;
+	mv += 1.0;
+	assert_eq!(mv.e, 3.0);
+	let mv: Mv = mv - 3.0;
+	assert_eq!(mv, -V3::E012 + 0.0);
+}
+
+#[test]
+fn scalars_stay_scalars() {
+	let v: V0 = V0 { e: 1.0 } + 2.0;
+	assert_eq!(v.e, 3.0);
+	let mut v = v;
+	v -= 0.5;
+	assert_eq!(v.e, 2.5);
+}
+
+#[test]
+fn scalar_each() {
+	let v = V1::from_array([1.0, 2.0, 3.0]);
+	assert_eq!(v.add_scalar_each(1.0), V1::from_array([2.0, 3.0, 4.0]));
+	assert_eq!(v.sub_scalar_each(1.0), V1::from_array([0.0, 1.0, 2.0]));
+	assert_eq!(v.add_scalar_each(2.5).sub_scalar_each(2.5), v);
+
+	let v = V2::E01.add_scalar_each(1.0);
+	assert_eq!(v.to_array(), [2.0, 1.0, 1.0]);
+	let mv = Mv::ZERO.sub_scalar_each(1.0);
+	assert!(mv.to_array().iter().all(|&c| c == -1.0));
+}
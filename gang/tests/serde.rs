@@ -0,0 +1,82 @@
+//! The compact sequence form of `gang::serde_seq`.
+
+#![cfg(all(feature = "serde", feature = "g3"))]
+
+mod common;
+
+use gang::g3::*;
+use serde::{Deserialize, Serialize};
+
+use crate::common::components;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Body {
+	#[serde(with = "gang::serde_seq")]
+	position: V1,
+	#[serde(with = "gang::serde_seq")]
+	spin: V2,
+	#[serde(with = "gang::serde_seq")]
+	orientation: Rot,
+	#[serde(with = "gang::serde_seq")]
+	state: Mv,
+}
+
+fn body() -> Body {
+	Body {
+		position: V1::new(1.0, -2.0, 0.5),
+		spin: V2::from_array(components(1)),
+		orientation: Rot::from_v2_angle(V2::E02, 0.5),
+		state: Mv::from_array(components(2)),
+	}
+}
+
+#[test]
+fn round_trip() {
+	let body = body();
+	let json = serde_json::to_string(&body).unwrap();
+	assert_eq!(serde_json::from_str::<Body>(&json).unwrap(), body);
+
+	let value = serde_json::to_value(&body).unwrap();
+	assert_eq!(value["position"], serde_json::json!([1.0, -2.0, 0.5]));
+	assert_eq!(value["spin"], serde_json::json!(body.spin.to_array()));
+	let rot = body.orientation;
+	assert_eq!(
+		value["orientation"],
+		serde_json::json!([rot.e, rot.e01, rot.e02, rot.e12])
+	);
+	assert_eq!(value["state"], serde_json::json!(body.state.to_array()));
+}
+
+#[test]
+fn wrong_length() {
+	let mut value = serde_json::to_value(body()).unwrap();
+	value["spin"] = serde_json::json!([1.0, 2.0]);
+	let err = serde_json::from_value::<Body>(value.clone()).unwrap_err();
+	assert!(
+		err.to_string()
+			.contains("invalid length 2, expected a sequence of 3 components"),
+		"{err}"
+	);
+
+	value["spin"] = serde_json::json!([1.0, 2.0, 3.0, 4.0]);
+	assert!(serde_json::from_value::<Body>(value.clone()).is_err());
+
+	value["spin"] = serde_json::json!([1.0, 2.0, 3.0]);
+	value["state"] = serde_json::json!(vec![0.0; 7]);
+	let err = serde_json::from_value::<Body>(value).unwrap_err();
+	assert!(
+		err.to_string()
+			.contains("expected a sequence of 8 components"),
+		"{err}"
+	);
+
+	let err = serde_json::from_str::<Body>(
+		r#"{"position": [1.0, 2.0, 3.0], "spin": [0.0, 0.0, 0.0], "orientation": [1.0, 0.0, 0.0], "state": [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]}"#,
+	)
+	.unwrap_err();
+	assert!(
+		err.to_string()
+			.contains("expected a sequence of 4 components"),
+		"{err}"
+	);
+}
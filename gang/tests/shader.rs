@@ -0,0 +1,321 @@
+//! The shader sources of every enabled dimension parse and validate, and their functions compute
+//! the same values as the Rust products.
+
+mod common;
+
+use naga::{
+	front::glsl,
+	valid::{Capabilities, ValidationFlags, Validator},
+	Module, ShaderStage,
+};
+
+fn validate(module: &naga::Module) {
+	Validator::new(ValidationFlags::all(), Capabilities::all())
+		.validate(module)
+		.unwrap();
+}
+
+/// Parses and validates on a thread with a large stack, as the frontends recurse on the long sums
+fn check(wgsl: &'static str, glsl: &'static str) -> [Module; 2] {
+	std::thread::Builder::new()
+		.stack_size(256 << 20)
+		.spawn(move || check_on_thread(wgsl, glsl))
+		.unwrap()
+		.join()
+		.unwrap()
+}
+
+fn check_on_thread(wgsl: &str, glsl: &str) -> [Module; 2] {
+	let wgsl_module =
+		naga::front::wgsl::parse_str(wgsl).unwrap_or_else(|e| panic!("{}", e.emit_to_string(wgsl)));
+	validate(&wgsl_module);
+
+	// the GLSL frontend needs a version and an entry point
+	let source = format!("#version 450\n{glsl}\nvoid main() {{ gl_Position = vec4(0.0); }}\n");
+	let glsl_module = glsl::Frontend::default()
+		.parse(&glsl::Options::from(ShaderStage::Vertex), &source)
+		.unwrap_or_else(|e| panic!("{}", e.emit_to_string(&source)));
+	validate(&glsl_module);
+
+	[wgsl_module, glsl_module]
+}
+
+/// Interprets the straight-line functions of the shader sources: arguments copied to local
+/// variables, then sums of products of their members
+#[cfg(any(feature = "g2", feature = "g3", feature = "g4"))]
+mod interpreter {
+	use std::collections::HashMap;
+
+	use naga::{
+		BinaryOperator, Expression, Handle, Literal, LocalVariable, Module, Statement, TypeInner,
+		UnaryOperator,
+	};
+
+	#[derive(Clone, Debug)]
+	enum Value {
+		Float(f32),
+		Struct(Vec<Value>),
+		Local(Handle<LocalVariable>),
+	}
+
+	impl Value {
+		fn float(&self) -> f32 {
+			match self {
+				Value::Float(x) => *x,
+				other => panic!("expected a float, got {other:?}"),
+			}
+		}
+
+		fn components(self) -> Vec<f32> {
+			match self {
+				Value::Struct(members) => members.iter().map(Value::float).collect(),
+				other => panic!("expected a struct, got {other:?}"),
+			}
+		}
+	}
+
+	struct Interpreter<'a> {
+		function: &'a naga::Function,
+		args: &'a [Vec<f32>],
+		values: HashMap<Handle<Expression>, Value>,
+		locals: HashMap<Handle<LocalVariable>, Value>,
+	}
+
+	impl Interpreter<'_> {
+		fn value(&mut self, handle: Handle<Expression>) -> Value {
+			if let Some(value) = self.values.get(&handle) {
+				return value.clone();
+			}
+			let value = match self.function.expressions[handle] {
+				Expression::FunctionArgument(i) => Value::Struct(
+					self.args[i as usize]
+						.iter()
+						.map(|&x| Value::Float(x))
+						.collect(),
+				),
+				Expression::LocalVariable(local) => Value::Local(local),
+				Expression::Load { pointer } => match self.value(pointer) {
+					Value::Local(local) => self.locals[&local].clone(),
+					other => panic!("expected a pointer, got {other:?}"),
+				},
+				Expression::AccessIndex { base, index } => match self.value(base) {
+					Value::Struct(members) => members[index as usize].clone(),
+					other => panic!("expected a struct, got {other:?}"),
+				},
+				Expression::Literal(Literal::F32(x)) => Value::Float(x),
+				Expression::Unary {
+					op: UnaryOperator::Negate,
+					expr,
+				} => Value::Float(-self.value(expr).float()),
+				Expression::Binary { op, left, right } => {
+					let (left, right) = (self.value(left).float(), self.value(right).float());
+					Value::Float(match op {
+						BinaryOperator::Add => left + right,
+						BinaryOperator::Subtract => left - right,
+						BinaryOperator::Multiply => left * right,
+						op => panic!("unsupported operator {op:?}"),
+					})
+				}
+				Expression::Compose { ref components, .. } => {
+					Value::Struct(components.iter().map(|&c| self.value(c)).collect())
+				}
+				ref other => panic!("unsupported expression {other:?}"),
+			};
+			self.values.insert(handle, value.clone());
+			value
+		}
+	}
+
+	/// Calls the function `name` of `module`, with the components of its struct arguments
+	pub fn call(module: &Module, name: &str, args: &[Vec<f32>]) -> Vec<f32> {
+		let (_, function) = module
+			.functions
+			.iter()
+			.find(|(_, f)| f.name.as_deref() == Some(name))
+			.unwrap_or_else(|| panic!("no function {name}"));
+		let mut interpreter = Interpreter {
+			function,
+			args,
+			values: HashMap::new(),
+			locals: HashMap::new(),
+		};
+		for statement in function.body.iter() {
+			match *statement {
+				Statement::Emit(ref range) => {
+					for handle in range.clone() {
+						interpreter.value(handle);
+					}
+				}
+				Statement::Store { pointer, value } => {
+					let Value::Local(local) = interpreter.value(pointer) else {
+						panic!("store to a non-local in {name}");
+					};
+					let value = interpreter.value(value);
+					interpreter.locals.insert(local, value);
+				}
+				Statement::Return { value: Some(value) } => {
+					return interpreter.value(value).components();
+				}
+				ref other => panic!("unsupported statement {other:?} in {name}"),
+			}
+		}
+		panic!("{name} doesn't return")
+	}
+
+	/// The member names of the struct `name` of `module`, in order
+	pub fn members(module: &Module, name: &str) -> Vec<String> {
+		module
+			.types
+			.iter()
+			.find_map(|(_, ty)| match &ty.inner {
+				TypeInner::Struct { members, .. } if ty.name.as_deref() == Some(name) => {
+					Some(members.iter().map(|m| m.name.clone().unwrap()).collect())
+				}
+				_ => None,
+			})
+			.unwrap_or_else(|| panic!("no struct {name}"))
+	}
+
+	#[track_caller]
+	pub fn assert_close(name: &str, shader: &[f32], cpu: &[f32]) {
+		assert_eq!(shader.len(), cpu.len(), "{name}");
+		for (s, c) in shader.iter().zip(cpu) {
+			assert!(
+				(s - c).abs() <= 1e-5 * c.abs().max(1.0),
+				"{name}: {shader:?} != {cpu:?}"
+			);
+		}
+	}
+}
+
+macro_rules! check_shaders {
+	($($feature:literal => $module:ident;)*) => {
+		$(
+			#[cfg(feature = $feature)]
+			#[test]
+			fn $module() {
+				check(gang::$module::WGSL, gang::$module::GLSL);
+			}
+		)*
+	};
+}
+
+check_shaders! {
+	"g1" => g1;
+	"g2" => g2;
+	"g3" => g3;
+	"g4" => g4;
+	"g5" => g5;
+}
+
+/// Every function of the default sources against the products of the module, through the WGSL
+/// and GLSL frontends
+macro_rules! check_against_cpu {
+	($($feature:literal => $module:ident, [$($rot:ident),*], rotate [$($k:literal: $kind:ident),*], wedge [$($i:literal: $lhs:ident ^ $j:literal: $rhs:ident),*];)*) => {
+		$(
+			#[cfg(feature = $feature)]
+			mod $module {
+				use gang::{$module::*, Rotate, Wedge};
+
+				use super::{
+					check,
+					interpreter::{assert_close, call, members},
+					Module,
+				};
+				use crate::common::components;
+
+				crate::common::rotors!();
+
+				/// The components of `rot` in the order of the shader struct
+				fn rot_components(module: &Module, rot: Rot) -> Vec<f32> {
+					let fields = [$((stringify!($rot), rot.$rot)),*];
+					members(module, "Rot")
+						.iter()
+						.map(|m| fields.iter().find(|(name, _)| name == m).unwrap().1)
+						.collect()
+				}
+
+				#[test]
+				fn matches_cpu() {
+					for module in check(WGSL, GLSL) {
+						let mut checked = 0;
+						let rotors = rotors();
+						for (lhs, rhs) in rotors.iter().zip(rotors.iter().rev()) {
+							let args = [rot_components(&module, *lhs), rot_components(&module, *rhs)];
+							let shader = call(&module, "rot_mul_rot", &args);
+							assert_close("rot_mul_rot", &shader, &rot_components(&module, *lhs * *rhs));
+						}
+						checked += 1;
+
+						$(
+							let name = concat!("rot_rotate_v", $k);
+							for (seed, rot) in rotors.iter().enumerate() {
+								let v = $kind::from_array(components(seed));
+								let args = [rot_components(&module, *rot), v.to_array().to_vec()];
+								assert_close(name, &call(&module, name, &args), &rot.rotate(v).to_array());
+							}
+							checked += 1;
+						)*
+
+						$(
+							let name = concat!("v", $i, "_wedge_v", $j);
+							for seed in 0..4 {
+								let (lhs, rhs) = ($lhs::from_array(components(seed)), $rhs::from_array(components(seed + 5)));
+								let args = [lhs.to_array().to_vec(), rhs.to_array().to_vec()];
+								assert_close(name, &call(&module, name, &args), &lhs.wedge(rhs).to_array());
+							}
+							checked += 1;
+						)*
+
+						// besides the entry point needed by the GLSL frontend
+						let functions = module
+							.functions
+							.iter()
+							.filter(|(_, f)| f.name.as_deref() != Some("main"))
+							.count();
+						assert_eq!(checked, functions, "some functions are not checked");
+					}
+				}
+			}
+		)*
+	};
+}
+
+check_against_cpu! {
+	"g2" => g2,
+		[e, e01],
+		rotate [0: V0, 1: V1, 2: V2],
+		wedge [
+			0: V0 ^ 0: V0, 0: V0 ^ 1: V1, 0: V0 ^ 2: V2, 1: V1 ^ 0: V0, 1: V1 ^ 1: V1, 2: V2 ^ 0: V0
+		];
+	"g3" => g3,
+		[e, e01, e02, e12],
+		rotate [0: V0, 1: V1, 2: V2, 3: V3],
+		wedge [
+			0: V0 ^ 0: V0, 0: V0 ^ 1: V1, 0: V0 ^ 2: V2, 0: V0 ^ 3: V3, 1: V1 ^ 0: V0, 1: V1 ^ 1: V1,
+			1: V1 ^ 2: V2, 2: V2 ^ 0: V0, 2: V2 ^ 1: V1, 3: V3 ^ 0: V0
+		];
+	"g4" => g4,
+		[e, e01, e02, e03, e12, e13, e23, e0123],
+		rotate [0: V0, 1: V1, 2: V2, 3: V3, 4: V4],
+		wedge [
+			0: V0 ^ 0: V0, 0: V0 ^ 1: V1, 0: V0 ^ 2: V2, 0: V0 ^ 3: V3, 0: V0 ^ 4: V4, 1: V1 ^ 0: V0,
+			1: V1 ^ 1: V1, 1: V1 ^ 2: V2, 1: V1 ^ 3: V3, 2: V2 ^ 0: V0, 2: V2 ^ 1: V1, 2: V2 ^ 2: V2,
+			3: V3 ^ 0: V0, 3: V3 ^ 1: V1, 4: V4 ^ 0: V0
+		];
+}
+
+/// From 6 dimensions, the modules have no shader sources, but the macros still produce them
+#[test]
+fn higher_dimensions() {
+	check(gang::wgsl!(6), gang::glsl!(6));
+	check(gang::wgsl!(7), gang::glsl!(7));
+}
+
+#[test]
+fn custom_options() {
+	check(
+		gang::wgsl!(5, products = [rotate], grades = [1, 2, 3]),
+		gang::glsl!(5, products = [rotate], grades = [1, 2, 3]),
+	);
+}
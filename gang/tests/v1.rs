@@ -0,0 +1,133 @@
+//! The vector geometry helpers of `V1`, including their results on zero inputs.
+
+#![cfg(any(feature = "g2", feature = "g3", feature = "g4", feature = "g5"))]
+
+macro_rules! check_v1 {
+	($module:ident) => {
+		mod $module {
+			use gang::$module::*;
+			use gang::Wedge;
+
+			fn v(components: &[f32]) -> V1 {
+				let mut arr = V1::ZERO.to_array();
+				arr[..components.len()].copy_from_slice(components);
+				V1::from_array(arr)
+			}
+
+			#[test]
+			fn dot_norm_and_distance() {
+				let a = v(&[1.0, 2.0]);
+				let b = v(&[3.0, -1.0]);
+				assert_eq!(a.dot(b), 1.0);
+				assert_eq!(a.dot(b), b.dot(a));
+				assert_eq!(a.norm_squared(), 5.0);
+				assert_eq!(a.distance(b), (4.0f32 + 9.0).sqrt());
+				assert_eq!(a.distance(a), 0.0);
+			}
+
+			#[test]
+			fn lerp() {
+				let a = v(&[1.0, 2.0]);
+				let b = v(&[3.0, -2.0]);
+				assert_eq!(a.lerp(b, 0.0), a);
+				assert_eq!(a.lerp(b, 1.0), b);
+				assert_eq!(a.lerp(b, 0.5), v(&[2.0, 0.0]));
+				assert_eq!(a.lerp(b, 2.0), v(&[5.0, -6.0]));
+			}
+
+			#[test]
+			fn project_and_reject() {
+				let a = v(&[3.0, 4.0]);
+				let b = v(&[2.0, 0.0]);
+				assert!(a.project_onto(b).abs_diff_eq(v(&[3.0, 0.0]), 1e-6));
+				assert!(a.reject_from(b).abs_diff_eq(v(&[0.0, 4.0]), 1e-6));
+				assert!((a.project_onto(b) + a.reject_from(b)).abs_diff_eq(a, 1e-6));
+				assert!(a.reject_from(b).dot(b).abs() < 1e-6);
+				// the rejection is orthogonal, so its wedge with `b` is the same as for `a`
+				assert!(a.reject_from(b).wedge(b).abs_diff_eq(a.wedge(b), 1e-6));
+
+				assert!(a
+					.project_onto(V1::ZERO)
+					.to_array()
+					.iter()
+					.all(|c| c.is_nan()));
+				assert!(a
+					.reject_from(V1::ZERO)
+					.to_array()
+					.iter()
+					.all(|c| c.is_nan()));
+				assert_eq!(V1::ZERO.project_onto(b), V1::ZERO);
+			}
+
+			#[test]
+			fn reflect() {
+				let a = v(&[3.0, 4.0]);
+				let normal = v(&[0.0, 2.0]);
+				assert!(a.reflect(normal).abs_diff_eq(v(&[3.0, -4.0]), 1e-6));
+				assert!(a.reflect(normal).reflect(normal).abs_diff_eq(a, 1e-6));
+				assert!((a.reflect(normal).norm() - a.norm()).abs() < 1e-6);
+				assert!(a.reflect(V1::ZERO).to_array().iter().all(|c| c.is_nan()));
+			}
+
+			#[test]
+			fn angle_between() {
+				let a = v(&[1.0, 0.0]);
+				let b = v(&[1.0, 1.0]);
+				assert!((a.angle_between(b) - core::f32::consts::FRAC_PI_4).abs() < 1e-6);
+				assert_eq!(a.angle_between(b), b.angle_between(a));
+				assert_eq!(a.angle_between(a * 3.0), 0.0);
+				assert!((a.angle_between(-a) - core::f32::consts::PI).abs() < 1e-6);
+				assert!(
+					(a.angle_between(v(&[0.0, -2.0])) - core::f32::consts::FRAC_PI_2).abs() < 1e-6
+				);
+				assert_eq!(a.angle_between(V1::ZERO), 0.0);
+				assert_eq!(V1::ZERO.angle_between(V1::ZERO), 0.0);
+			}
+
+			#[test]
+			fn project_onto_plane() {
+				let a = v(&[3.0, 4.0]);
+				let plane = v(&[1.0, 0.0]).wedge(v(&[1.0, 1.0]));
+				// the plane of the first two basis vectors contains `a`
+				assert!(a.project_onto_plane(plane).abs_diff_eq(a, 1e-6));
+				assert!(a.project_onto_plane(-plane * 3.0).abs_diff_eq(a, 1e-6));
+				assert!(V1::ZERO
+					.project_onto_plane(plane)
+					.abs_diff_eq(V1::ZERO, 1e-6));
+				assert!(a
+					.project_onto_plane(V2::ZERO)
+					.to_array()
+					.iter()
+					.all(|c| c.is_nan()));
+			}
+		}
+	};
+}
+
+#[cfg(feature = "g2")]
+check_v1!(g2);
+#[cfg(feature = "g3")]
+check_v1!(g3);
+#[cfg(feature = "g4")]
+check_v1!(g4);
+#[cfg(feature = "g5")]
+check_v1!(g5);
+
+/// Projections onto planes that don't contain the vector
+#[cfg(feature = "g3")]
+#[test]
+fn project_onto_plane_g3() {
+	use gang::g3::*;
+	use gang::Wedge;
+
+	let a = V1::from_array([1.0, 2.0, 3.0]);
+	let plane = V1::E0.wedge(V1::E1) * 2.0;
+	assert!(a
+		.project_onto_plane(plane)
+		.abs_diff_eq(V1::from_array([1.0, 2.0, 0.0]), 1e-6));
+	let plane = V1::from_array([1.0, 1.0, 0.0]).wedge(V1::E2);
+	let projected = a.project_onto_plane(plane);
+	assert!(projected.abs_diff_eq(V1::from_array([1.5, 1.5, 3.0]), 1e-6));
+	// what is left is along the normal of the plane
+	assert!((a - projected).abs_diff_eq(V1::from_array([-0.5, 0.5, 0.0]), 1e-6));
+}
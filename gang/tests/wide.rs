@@ -0,0 +1,124 @@
+//! The structure-of-arrays types give the same results as the scalar types on every lane.
+
+#![cfg(any(feature = "g3", feature = "g4"))]
+
+mod common;
+
+macro_rules! check_wide {
+	($module:ident, [$($kind:ident, $wide:ident;)*], [$($lhs:ident * $rhs:ident -> $lhs_wide:ident * $rhs_wide:ident;)*], $rot_wide:ident, $mv_wide:ident, $width:literal) => {
+		mod $module {
+			use gang::$module::*;
+
+			use crate::common::components;
+
+			crate::common::rotors!();
+
+			fn lanes() -> [Rot; $width] {
+				core::array::from_fn(|i| rotors()[i])
+			}
+
+			#[test]
+			fn rot_mul() {
+				let (a, b) = (lanes(), lanes().map(|r| r * r));
+				let wide = $rot_wide::from_lanes(a) * $rot_wide::from_lanes(b);
+				for i in 0..$width {
+					assert!(wide.lane(i).abs_diff_eq(a[i] * b[i], 1e-5));
+				}
+			}
+
+			#[test]
+			fn rot_linear_ops() {
+				let (a, b) = ($rot_wide::from_lanes(lanes()), $rot_wide::splat(lanes()[1]));
+				for i in 0..$width {
+					assert!((a + b - b).lane(i).abs_diff_eq(a.lane(i), 1e-5));
+					assert!((a + a).lane(i).abs_diff_eq(a.lane(i) * 2.0, 1e-5));
+					assert_eq!((-a).lane(i), a.lane(i) * -1.0);
+				}
+			}
+
+			#[test]
+			fn mv() {
+				let values: [Mv; $width] = core::array::from_fn(|i| Mv::from_array(components(i)));
+				let (a, b) = ($mv_wide::from_lanes(values), $mv_wide::splat(values[2]));
+				assert_eq!(a.to_lanes(), values);
+				for i in 0..$width {
+					assert!((a + b - b).lane(i).abs_diff_eq(values[i], 1e-5));
+					assert!((a * 2.0).lane(i).abs_diff_eq(values[i] + values[i], 1e-5));
+					assert_eq!((-a).lane(i), -values[i]);
+					assert!((a.norm()[i] - values[i].norm()).abs() < 1e-5);
+				}
+				let mut c = a;
+				c.set_lane(1, Mv::ZERO);
+				c -= b;
+				assert_eq!(c.lane(1), -values[2]);
+			}
+
+			$(
+				#[test]
+				#[allow(non_snake_case)]
+				fn $wide() {
+					let values: [$kind; $width] = core::array::from_fn(|i| $kind::from_array(components(i + 3)));
+					let rotated = gang::Rotate::rotate($rot_wide::from_lanes(lanes()), $wide::from_lanes(values));
+					for i in 0..$width {
+						assert!(rotated.lane(i).abs_diff_eq(lanes()[i].rotate(values[i]), 1e-4));
+					}
+				}
+			)*
+
+			#[test]
+			fn normalize() {
+				$(
+					let values: [$kind; $width] = core::array::from_fn(|i| $kind::from_array(components(i + 8)));
+					let normalized = $wide::from_lanes(values).normalize();
+					for i in 0..$width {
+						assert!(normalized.lane(i).abs_diff_eq(values[i].normalize(), 1e-6));
+					}
+				)*
+
+				let rotors: [Rot; $width] = core::array::from_fn(|i| lanes()[i] * (0.5 + i as f32));
+				let normalized = $rot_wide::from_lanes(rotors).normalize();
+				for i in 0..$width {
+					assert!(normalized.lane(i).abs_diff_eq(rotors[i].normalize(), 1e-6));
+					assert!(normalized.lane(i).abs_diff_eq(lanes()[i], 1e-5));
+				}
+
+				let values: [Mv; $width] = core::array::from_fn(|i| Mv::from_array(components(i)));
+				let normalized = $mv_wide::from_lanes(values).normalize();
+				for i in 0..$width {
+					assert!(normalized.lane(i).abs_diff_eq(values[i].normalize(), 1e-6));
+				}
+			}
+
+			#[test]
+			fn wedge() {
+				$(
+					let lhs: [$lhs; $width] = core::array::from_fn(|i| $lhs::from_array(components(i)));
+					let rhs: [$rhs; $width] = core::array::from_fn(|i| $rhs::from_array(components(i + 5)));
+					let wide = $lhs_wide::from_lanes(lhs).wedge($rhs_wide::from_lanes(rhs));
+					for i in 0..$width {
+						assert!(wide.lane(i).abs_diff_eq(lhs[i].wedge(rhs[i]), 1e-4));
+					}
+				)*
+			}
+		}
+	};
+}
+
+#[cfg(feature = "g3")]
+check_wide!(
+	g3,
+	[V0, V0x4; V1, V1x4; V2, V2x4; V3, V3x4;],
+	[V1 * V1 -> V1x4 * V1x4; V1 * V2 -> V1x4 * V2x4; V2 * V1 -> V2x4 * V1x4;],
+	Rotx4,
+	Mvx4,
+	4
+);
+#[cfg(feature = "g4")]
+check_wide!(
+	g4,
+	[V0, V0x8; V1, V1x8; V2, V2x8; V3, V3x8; V4, V4x8;],
+	[V1 * V1 -> V1x8 * V1x8; V1 * V3 -> V1x8 * V3x8; V2 * V2 -> V2x8 * V2x8;],
+	Rotx8,
+	Mvx8,
+	8
+);